@@ -0,0 +1,171 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::infrastructure::notifier::NotifierRules;
+use crate::terminal::components::list::{ActiveFilterState, Column, ServiceAction, SortOrder};
+use crate::terminal::keymap::{KeyAction, KeyList, Keymap, ViewAction};
+use crate::terminal::theme::Theme;
+
+const CONFIG_FILE: &str = "systemd-manager-tui/config.toml";
+
+/// Lines of journal shown in the log view, as many as `journalctl -e` shows.
+pub const DEFAULT_LOG_LINES: usize = 1000;
+
+/// View a unit given on the command line is opened in.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum StartView {
+    Status,
+    Log,
+    Details,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultTab {
+    System,
+    Session,
+}
+
+/// Preferences read from `$XDG_CONFIG_HOME/systemd-manager-tui/config.toml`, every key is optional.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FileConfig {
+    pub default_tab: Option<DefaultTab>,
+    /// List all units instead of only services.
+    pub all_units: bool,
+    pub state: Option<ActiveFilterState>,
+    pub columns: Option<Vec<Column>>,
+    pub sort: SortOrder,
+    pub log_lines: Option<usize>,
+    /// Seconds between refreshes of the unit list, 0 or missing to only refresh on demand.
+    pub refresh_interval: u64,
+    /// Actions asking for a y/n confirmation first.
+    pub confirm: Vec<ServiceAction>,
+    /// Name of the color theme, built-in or from `themes`.
+    pub theme: Option<String>,
+    /// User themes by name, each a `base` theme and the colors replaced in it.
+    pub themes: HashMap<String, HashMap<String, String>>,
+    /// Capture the mouse, true when missing; turn off to keep the terminal's own text selection.
+    pub mouse: Option<bool>,
+    pub notifier: NotifierRules,
+    /// Keys replacing the default ones of an action.
+    pub keys: HashMap<KeyAction, KeyList>,
+    /// Keys of the commands of the other screens.
+    pub view_keys: HashMap<ViewAction, KeyList>,
+}
+
+impl FileConfig {
+    fn path() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(base.join(CONFIG_FILE))
+    }
+
+    /// Reads the config file; a missing file gives the defaults.
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("Failed to read {}: {e}", path.display())),
+        };
+        toml::from_str(&content).map_err(|e| format!("Invalid config file {}: {e}", path.display()))
+    }
+}
+
+#[derive(Clone)]
+pub struct Config {
+    pub filter: String,
+    pub hosts: Vec<String>,
+    pub user: bool,
+    pub unit: Option<String>,
+    pub state: ActiveFilterState,
+    pub all: bool,
+    pub view: StartView,
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+    pub log_lines: usize,
+    pub refresh_interval: Option<Duration>,
+    pub confirm: Vec<ServiceAction>,
+    pub notifier: NotifierRules,
+    pub keymap: Rc<Keymap>,
+    pub theme: Option<String>,
+    pub themes: HashMap<String, Theme>,
+    pub mouse: bool,
+}
+
+impl Config {
+    pub fn new(file: FileConfig) -> Result<Self, String> {
+        if file.log_lines == Some(0) {
+            return Err("Invalid log-lines in the config file: at least 1 line is needed".to_string());
+        }
+        let keymap = Keymap::new(&file.keys, &file.view_keys)
+            .map_err(|e| format!("Invalid [keys] or [view-keys] in the config file: {e}"))?;
+        let themes = file
+            .themes
+            .iter()
+            .map(|(name, table)| {
+                Theme::from_table(table)
+                    .map(|theme| (name.clone(), theme))
+                    .map_err(|e| format!("Invalid [themes.{name}] in the config file: {e}"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            filter: String::new(),
+            hosts: Vec::new(),
+            user: file.default_tab == Some(DefaultTab::Session),
+            unit: None,
+            state: file.state.unwrap_or_default(),
+            all: file.all_units,
+            view: StartView::Status,
+            columns: file.columns.unwrap_or_else(|| Column::DEFAULT.to_vec()),
+            sort: file.sort,
+            log_lines: file.log_lines.unwrap_or(DEFAULT_LOG_LINES),
+            refresh_interval: (file.refresh_interval > 0).then(|| Duration::from_secs(file.refresh_interval)),
+            confirm: file.confirm,
+            notifier: file.notifier,
+            keymap: Rc::new(keymap),
+            theme: file.theme,
+            themes,
+            mouse: file.mouse.unwrap_or(true),
+        })
+    }
+
+    /// Theme to draw with: the one asked for, else monochrome when NO_COLOR is set, else dark.
+    pub fn theme(&self) -> Result<Theme, String> {
+        let Some(name) = &self.theme else {
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            return Ok(if no_color { Theme::monochrome() } else { Theme::dark() });
+        };
+        self.themes
+            .get(name)
+            .cloned()
+            .or_else(|| Theme::built_in(name))
+            .ok_or_else(|| {
+                let mut names: Vec<&str> = Theme::BUILT_IN.to_vec();
+                names.extend(self.themes.keys().map(String::as_str));
+                format!("Unknown theme '{name}', available: {}", names.join(", "))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_zero_log_lines() {
+        let file = FileConfig { log_lines: Some(0), ..FileConfig::default() };
+        assert!(Config::new(file).is_err());
+        let file = FileConfig { log_lines: Some(1), ..FileConfig::default() };
+        assert_eq!(Config::new(file).unwrap().log_lines, 1);
+    }
+}
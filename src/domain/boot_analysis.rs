@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+/// Manager timestamps of the last boot, in microseconds on the monotonic clock.
+///
+/// Firmware and loader timestamps are measured backwards from the kernel start, as systemd reports them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BootTimestamps {
+    pub firmware: u64,
+    pub loader: u64,
+    pub initrd: u64,
+    pub userspace: u64,
+    pub finish: u64,
+}
+
+impl BootTimestamps {
+    /// Boot phases with their duration, skipping the ones that were not measured.
+    pub fn phases(&self) -> Vec<(&'static str, u64)> {
+        let mut phases = Vec::new();
+        if self.firmware > 0 {
+            phases.push(("firmware", self.firmware.saturating_sub(self.loader)));
+        }
+        if self.loader > 0 {
+            phases.push(("loader", self.loader));
+        }
+        if self.initrd > 0 {
+            phases.push(("kernel", self.initrd));
+            phases.push(("initrd", self.userspace.saturating_sub(self.initrd)));
+        } else if self.userspace > 0 {
+            phases.push(("kernel", self.userspace));
+        }
+        if self.finish > 0 {
+            phases.push(("userspace", self.finish.saturating_sub(self.userspace)));
+        }
+        phases
+    }
+
+    pub fn total(&self) -> u64 {
+        self.firmware.max(self.loader) + self.finish
+    }
+
+    /// The manager sets the finish timestamp once the default target and its jobs are done.
+    pub fn is_finished(&self) -> bool {
+        self.finish > 0
+    }
+}
+
+/// Activation timestamps of a unit, in microseconds on the monotonic clock.
+#[derive(Clone, Debug, Default)]
+pub struct UnitActivation {
+    pub name: String,
+    /// `InactiveExitTimestampMonotonic`: when the unit started activating.
+    pub activating: u64,
+    /// `ActiveEnterTimestampMonotonic`: when the unit became active.
+    pub activated: u64,
+    pub after: Vec<String>,
+}
+
+impl UnitActivation {
+    pub fn duration(&self) -> u64 {
+        self.activated.saturating_sub(self.activating)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BlameOrder {
+    Duration,
+    Activated,
+    Name,
+}
+
+impl BlameOrder {
+    pub const ALL: [BlameOrder; 3] = [BlameOrder::Duration, BlameOrder::Activated, BlameOrder::Name];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BlameOrder::Duration => "duration",
+            BlameOrder::Activated => "activation time",
+            BlameOrder::Name => "name",
+        }
+    }
+
+    pub fn next(self) -> BlameOrder {
+        let index = BlameOrder::ALL.iter().position(|order| *order == self).unwrap_or(0);
+        BlameOrder::ALL[(index + 1) % BlameOrder::ALL.len()]
+    }
+}
+
+/// Units that took time to start, like `systemd-analyze blame`.
+pub fn blame(units: &[UnitActivation], order: BlameOrder) -> Vec<&UnitActivation> {
+    let mut blamed: Vec<&UnitActivation> =
+        units.iter().filter(|unit| unit.activating > 0 && unit.activated > unit.activating).collect();
+    match order {
+        BlameOrder::Duration => blamed.sort_by(|a, b| b.duration().cmp(&a.duration()).then(a.name.cmp(&b.name))),
+        BlameOrder::Activated => blamed.sort_by_key(|unit| unit.activated),
+        BlameOrder::Name => blamed.sort_by(|a, b| a.name.cmp(&b.name)),
+    }
+    blamed
+}
+
+/// Chain of units that delayed `target` the most, like `systemd-analyze critical-chain`.
+///
+/// Starting from the target, each step follows the `After=` dependency that became active last
+/// before the current unit started. Returns the chain from the target down, with the depth of each unit.
+pub fn critical_chain<'a>(units: &'a [UnitActivation], target: &str) -> Vec<(usize, &'a UnitActivation)> {
+    let by_name: HashMap<&str, &UnitActivation> = units.iter().map(|unit| (unit.name.as_str(), unit)).collect();
+
+    let mut chain = Vec::new();
+    let mut current = by_name.get(target).copied();
+    while let Some(unit) = current {
+        if unit.activated == 0 || chain.iter().any(|(_, linked): &(usize, &UnitActivation)| linked.name == unit.name) {
+            break;
+        }
+        chain.push((chain.len(), unit));
+
+        let started = if unit.activating > 0 { unit.activating } else { unit.activated };
+        current = unit
+            .after
+            .iter()
+            .filter_map(|name| by_name.get(name.as_str()).copied())
+            .filter(|dependency| dependency.activated > 0 && dependency.activated <= started)
+            .max_by_key(|dependency| dependency.activated);
+    }
+    chain
+}
+
+/// Duration in the style of `systemd-analyze`, such as "1min 2.345s", "1.234s" or "56ms".
+pub fn format_usec(usec: u64) -> String {
+    let msec = usec / 1000;
+    if msec < 1000 {
+        return format!("{msec}ms");
+    }
+    let minutes = msec / 60_000;
+    let seconds = format!("{}.{:03}s", (msec / 1000) % 60, msec % 1000);
+    if minutes > 0 {
+        format!("{minutes}min {seconds}")
+    } else {
+        seconds
+    }
+}
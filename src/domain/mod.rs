@@ -1,3 +1,15 @@
+pub mod boot_analysis;
 pub mod service;
 pub mod service_repository;
 pub mod service_state;
+pub mod transient_unit;
+pub mod unit_diff;
+pub mod unit_directives;
+pub mod unit_job;
+pub mod unit_process;
+pub mod unit_resources;
+pub mod unit_security;
+pub mod unit_signal;
+pub mod unit_status;
+pub mod unit_target;
+pub mod unit_template;
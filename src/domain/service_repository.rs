@@ -1,24 +1,73 @@
-use crate::infrastructure::systemd_service_adapter::ConnectionType;
 
+use super::boot_analysis::{BootTimestamps, UnitActivation};
 use super::service::Service;
+use super::unit_diff::UnitOverride;
+use super::unit_directives::Diagnostic;
+use super::transient_unit::TransientUnit;
+use super::unit_job::{JobMode, UnitJob};
+use super::unit_process::UnitProcess;
+use super::unit_resources::ResourceControl;
+use super::unit_security::SecuritySettings;
+use super::unit_status::UnitStatus;
+use super::unit_target::TargetUnit;
 use std::error::Error;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub trait ServiceRepository: Send + Sync {
     fn list_services(&self, filter: bool) -> Result<Vec<Service>, Box<dyn Error>>;
     fn unit_files_state(&self, services: Vec<Service>) -> Result<HashMap<String, String>, Box<dyn Error>>;
     fn list_service_files(&self) -> Result<Vec<Service>, Box<dyn Error>>;
+    fn list_unit_overrides(&self) -> Result<Vec<UnitOverride>, Box<dyn Error>>;
     fn get_unit(&self, name: &str) -> Result<Service, Box<dyn Error>>;
-    fn get_service_log(&self, name: &str) -> Result<String, Box<dyn Error>>;
-    fn start_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
-    fn stop_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
-    fn restart_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
+    fn get_service_log(&self, name: &str, lines: usize) -> Result<String, Box<dyn Error>>;
+    fn get_service_log_tail(&self, name: &str, lines: usize) -> Result<String, Box<dyn Error>>;
+    fn unit_status(&self, name: &str) -> Result<UnitStatus, Box<dyn Error>>;
+    fn security_settings(&self, name: &str) -> Result<SecuritySettings, Box<dyn Error>>;
+    fn start_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn stop_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn reload_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn reload_or_restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn try_restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn reload_or_try_restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn Error>>;
+    fn can_reload(&self, name: &str) -> Result<bool, Box<dyn Error>>;
     fn enable_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
     fn disable_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
     fn mask_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
     fn unmask_service(&self, name: &str) -> Result<Service, Box<dyn Error>>;
+    fn reset_failed_unit(&self, name: &str) -> Result<Service, Box<dyn Error>>;
+    fn reset_failed(&self) -> Result<(), Box<dyn Error>>;
+    fn kill_unit(&self, name: &str, whom: &str, signal: i32) -> Result<(), Box<dyn Error>>;
+    fn unit_processes(&self, name: &str) -> Result<Vec<UnitProcess>, Box<dyn Error>>;
+    fn kill_process(&self, pid: u32, signal: i32) -> Result<(), Box<dyn Error>>;
+    fn default_target(&self) -> Result<String, Box<dyn Error>>;
+    fn set_default_target(&self, name: &str) -> Result<(), Box<dyn Error>>;
+    fn list_targets(&self) -> Result<Vec<TargetUnit>, Box<dyn Error>>;
+    fn isolate_target(&self, name: &str) -> Result<Service, Box<dyn Error>>;
+    fn boot_timestamps(&self) -> Result<BootTimestamps, Box<dyn Error>>;
+    fn unit_activations(&self) -> Result<Vec<UnitActivation>, Box<dyn Error>>;
+    /// Starts the command as a transient unit and returns the name of the unit that runs it.
+    fn start_transient_unit(&self, unit: &TransientUnit) -> Result<String, Box<dyn Error>>;
+    fn resource_controls(&self, name: &str) -> Result<Vec<(ResourceControl, u64)>, Box<dyn Error>>;
+    /// Runtime changes are lost on reboot; persistent ones are written to a drop-in in `system.control`.
+    fn set_resource_controls(&self, name: &str, runtime: bool, values: &[(ResourceControl, u64)]) -> Result<(), Box<dyn Error>>;
+    fn list_jobs(&self) -> Result<Vec<UnitJob>, Box<dyn Error>>;
+    fn cancel_job(&self, id: u32) -> Result<(), Box<dyn Error>>;
+    fn clear_jobs(&self) -> Result<(), Box<dyn Error>>;
+    /// Calls `on_change` from a background thread whenever a job is queued or finishes, replacing
+    /// the previous callback, until `unwatch_jobs`.
+    fn watch_jobs(&self, on_change: Box<dyn Fn() + Send>) -> Result<(), Box<dyn Error>>;
+    fn unwatch_jobs(&self) -> Result<(), Box<dyn Error>>;
     fn reload_daemon(&self) -> Result<(), Box<dyn std::error::Error>>;
-    fn change_connection(&mut self, connection_type: ConnectionType) -> Result<(), zbus::Error>;
     fn systemctl_cat(&self, name: &str) -> Result<String, Box<dyn Error>>;
     fn get_active_enter_timestamp(&self, name: &str) -> Result<u64, Box<dyn Error>>;
+    fn unit_file_directory(&self) -> Result<PathBuf, Box<dyn Error>>;
+    fn create_unit_file(&self, file_name: &str, content: &str) -> Result<PathBuf, Box<dyn Error>>;
+    fn write_unit_file(&self, file_name: &str, content: &str) -> Result<PathBuf, Box<dyn Error>>;
+    fn unit_fragment_path(&self, name: &str) -> Result<String, Box<dyn Error>>;
+    fn unit_drop_in_paths(&self, name: &str) -> Result<Vec<String>, Box<dyn Error>>;
+    fn vendor_unit_path(&self, name: &str) -> Option<String>;
+    fn read_unit_file(&self, path: &str) -> Result<String, Box<dyn Error>>;
+    fn verify_unit_file(&self, file_name: &str, content: &str) -> Result<Vec<Diagnostic>, Box<dyn Error>>;
 }
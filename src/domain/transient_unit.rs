@@ -0,0 +1,168 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TransientKind {
+    /// The manager forks and supervises the command, like `systemd-run`.
+    Service,
+    /// The command is forked here and its process moved into a new scope, like `systemd-run --scope`.
+    Scope,
+}
+
+impl TransientKind {
+    pub const ALL: [TransientKind; 2] = [TransientKind::Service, TransientKind::Scope];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TransientKind::Service => "service",
+            TransientKind::Scope => "scope",
+        }
+    }
+}
+
+/// A command to run as a transient unit, as typed in the run dialog.
+#[derive(Clone, Debug)]
+pub struct TransientUnit {
+    pub kind: TransientKind,
+    pub name: String,
+    pub command: String,
+    pub working_directory: String,
+    /// Space separated `KEY=VALUE` assignments.
+    pub environment: String,
+    pub memory_max: String,
+    pub cpu_quota: String,
+    /// `OnCalendar=` of a transient timer that triggers the service instead of starting it now.
+    pub on_calendar: String,
+}
+
+impl TransientUnit {
+    /// Name with the unit suffix, generated like `systemd-run` does when left empty.
+    pub fn unit_name(&self) -> String {
+        let suffix = format!(".{}", self.kind.as_str());
+        let name = self.name.trim();
+        let name = if name.is_empty() {
+            let micros = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_micros();
+            format!("run-u{}", micros % 1_000_000_000)
+        } else {
+            name.strip_suffix(&suffix).unwrap_or(name).to_string()
+        };
+        format!("{name}{suffix}")
+    }
+
+    /// Name of the timer that triggers the service, if one was asked for.
+    pub fn timer_name(&self, unit_name: &str) -> Option<String> {
+        let base = unit_name.strip_suffix(".service")?;
+        (!self.on_calendar.trim().is_empty()).then(|| format!("{base}.timer"))
+    }
+
+    pub fn argv(&self) -> Vec<String> {
+        split_words(&self.command)
+    }
+
+    pub fn environment(&self) -> Vec<String> {
+        split_words(&self.environment)
+    }
+
+    pub fn memory_max(&self) -> Result<Option<u64>, String> {
+        let value = self.memory_max.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        parse_bytes(value).map(Some).ok_or_else(|| format!("Invalid MemoryMax '{value}'"))
+    }
+
+    /// `CPUQuota=` as `CPUQuotaPerSecUSec`.
+    pub fn cpu_quota(&self) -> Result<Option<u64>, String> {
+        let value = self.cpu_quota.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        parse_cpu_quota(value).map(Some).ok_or_else(|| format!("Invalid CPUQuota '{value}', expected a percentage such as 50%"))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.contains(|c: char| c == '/' || c.is_whitespace()) {
+            return Err("The unit name can not contain '/' or whitespace".to_string());
+        }
+        if self.argv().is_empty() {
+            return Err("A command is required".to_string());
+        }
+        let working_directory = self.working_directory.trim();
+        if !working_directory.is_empty() && !working_directory.starts_with('/') && working_directory != "~" {
+            return Err("The working directory must be an absolute path".to_string());
+        }
+        if let Some(assignment) = self.environment().iter().find(|assignment| !assignment.contains('=')) {
+            return Err(format!("Invalid environment assignment '{assignment}', expected KEY=VALUE"));
+        }
+        if self.kind == TransientKind::Scope && !self.on_calendar.trim().is_empty() {
+            return Err("Only services can be triggered by a timer".to_string());
+        }
+        self.memory_max()?;
+        self.cpu_quota()?;
+        Ok(())
+    }
+}
+
+/// Splits a command line on whitespace, keeping single or double quoted words together.
+pub fn split_words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some('"'), '\\') => {
+                if let Some(escaped) = chars.next() {
+                    word.push(escaped);
+                }
+            }
+            (Some(_), c) => word.push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    word.push(escaped);
+                    in_word = true;
+                }
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    words
+}
+
+/// Parses a size such as "512M" or "2G" with the binary suffixes systemd accepts.
+pub fn parse_bytes(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (number, multiplier) = match value.chars().last()? {
+        'K' | 'k' => (&value[..value.len() - 1], 1u64 << 10),
+        'M' | 'm' => (&value[..value.len() - 1], 1 << 20),
+        'G' | 'g' => (&value[..value.len() - 1], 1 << 30),
+        'T' | 't' => (&value[..value.len() - 1], 1 << 40),
+        'B' | 'b' => (&value[..value.len() - 1], 1),
+        _ => (value, 1),
+    };
+    number.trim().parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+/// Parses a CPU quota such as "50%" or "200%" into CPU time per second, in microseconds.
+pub fn parse_cpu_quota(value: &str) -> Option<u64> {
+    let percent: u64 = value.trim().strip_suffix('%')?.trim().parse().ok()?;
+    (percent > 0).then(|| percent * 10_000)
+}
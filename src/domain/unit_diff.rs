@@ -0,0 +1,249 @@
+const CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DiffKind {
+    Hunk,
+    Context,
+    Added,
+    Removed,
+}
+
+#[derive(Clone, Debug)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct UnitDelta {
+    pub vendor_path: Option<String>,
+    pub fragment_path: String,
+    pub drop_in_paths: Vec<String>,
+    pub vendor: String,
+    pub effective: String,
+}
+
+impl UnitDelta {
+    /// The repository resolves both paths, so equal files have equal strings.
+    pub fn is_customized(&self) -> bool {
+        self.vendor_path.as_deref() != Some(self.fragment_path.as_str()) || !self.drop_in_paths.is_empty()
+    }
+
+    pub fn diff(&self) -> Vec<DiffLine> {
+        diff_lines(&self.vendor, &self.effective)
+    }
+}
+
+/// Line based unified diff with three lines of context around each change.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence table, filled from the end.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // (kind, old line, new line, text)
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((DiffKind::Context, i, j, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push((DiffKind::Removed, i, j, old[i]));
+            i += 1;
+        } else {
+            ops.push((DiffKind::Added, i, j, new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| op.0 != DiffKind::Context)
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut output = Vec::new();
+    let mut index = 0;
+    while index < changed.len() {
+        let start = changed[index].saturating_sub(CONTEXT);
+        let mut end = changed[index];
+        while index + 1 < changed.len() && changed[index + 1] <= end + 2 * CONTEXT + 1 {
+            index += 1;
+            end = changed[index];
+        }
+        let end = (end + CONTEXT + 1).min(ops.len());
+        index += 1;
+
+        let hunk = &ops[start..end];
+        let old_count = hunk.iter().filter(|op| op.0 != DiffKind::Added).count();
+        let new_count = hunk.iter().filter(|op| op.0 != DiffKind::Removed).count();
+        // Like diff -u, an empty side starts at the line before the hunk.
+        let start = |line: usize, count: usize| if count == 0 { line } else { line + 1 };
+        output.push(DiffLine {
+            kind: DiffKind::Hunk,
+            text: format!(
+                "@@ -{},{old_count} +{},{new_count} @@",
+                start(hunk[0].1, old_count),
+                start(hunk[0].2, new_count)
+            ),
+        });
+        output.extend(hunk.iter().map(|(kind, _, _, text)| {
+            let prefix = match kind {
+                DiffKind::Added => '+',
+                DiffKind::Removed => '-',
+                _ => ' ',
+            };
+            DiffLine { kind: *kind, text: format!("{prefix}{text}") }
+        }));
+    }
+
+    output
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DeltaCategory {
+    Masked,
+    Equivalent,
+    Redirected,
+    Overridden,
+    Extended,
+}
+
+impl DeltaCategory {
+    pub const ALL: [DeltaCategory; 5] = [
+        DeltaCategory::Masked,
+        DeltaCategory::Equivalent,
+        DeltaCategory::Redirected,
+        DeltaCategory::Overridden,
+        DeltaCategory::Extended,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeltaCategory::Masked => "MASKED",
+            DeltaCategory::Equivalent => "EQUIVALENT",
+            DeltaCategory::Redirected => "REDIRECTED",
+            DeltaCategory::Overridden => "OVERRIDDEN",
+            DeltaCategory::Extended => "EXTENDED",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UnitOverride {
+    pub name: String,
+    pub category: DeltaCategory,
+    /// File that takes effect: the overriding unit, the mask symlink or the drop-in.
+    pub path: String,
+    /// File being overridden, usually the vendor unit.
+    pub target: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(numbers: std::ops::Range<usize>) -> Vec<String> {
+        numbers.map(|number| format!("line {number}")).collect()
+    }
+
+    fn text(lines: &[String]) -> String {
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+
+    fn render(diff: &[DiffLine]) -> Vec<&str> {
+        diff.iter().map(|line| line.text.as_str()).collect()
+    }
+
+    #[test]
+    fn empty_and_equal_inputs_have_no_hunks() {
+        assert!(diff_lines("", "").is_empty());
+        let unit = text(&numbered(1..5));
+        assert!(diff_lines(&unit, &unit).is_empty());
+    }
+
+    #[test]
+    fn insertions_into_an_empty_file() {
+        let diff = diff_lines("", "[Unit]\nDescription=x\n");
+        assert_eq!(render(&diff), ["@@ -0,0 +1,2 @@", "+[Unit]", "+Description=x"]);
+        assert_eq!(diff[1].kind, DiffKind::Added);
+    }
+
+    #[test]
+    fn removal_of_every_line() {
+        let diff = diff_lines("a\nb\n", "");
+        assert_eq!(render(&diff), ["@@ -1,2 +0,0 @@", "-a", "-b"]);
+    }
+
+    #[test]
+    fn insertion_keeps_three_lines_of_context() {
+        let old = numbered(1..11);
+        let mut new = old.clone();
+        new.insert(5, "inserted".to_string());
+
+        let diff = diff_lines(&text(&old), &text(&new));
+        assert_eq!(
+            render(&diff),
+            ["@@ -3,6 +3,7 @@", " line 3", " line 4", " line 5", "+inserted", " line 6", " line 7", " line 8"]
+        );
+    }
+
+    #[test]
+    fn changes_with_overlapping_context_share_a_hunk() {
+        let old = numbered(1..21);
+        let mut new = old.clone();
+        new[3] = "changed 4".to_string();
+        new[9] = "changed 10".to_string();
+
+        let diff = diff_lines(&text(&old), &text(&new));
+        let hunks: Vec<&str> = render(&diff).into_iter().filter(|line| line.starts_with("@@")).collect();
+        assert_eq!(hunks, ["@@ -1,13 +1,13 @@"]);
+    }
+
+    #[test]
+    fn distant_changes_get_their_own_hunks() {
+        let old = numbered(1..31);
+        let mut new = old.clone();
+        new[3] = "changed 4".to_string();
+        new[20] = "changed 21".to_string();
+
+        let diff = diff_lines(&text(&old), &text(&new));
+        let hunks: Vec<&str> = render(&diff).into_iter().filter(|line| line.starts_with("@@")).collect();
+        assert_eq!(hunks, ["@@ -1,7 +1,7 @@", "@@ -18,7 +18,7 @@"]);
+    }
+
+    #[test]
+    fn customized_units() {
+        let vendor = UnitDelta {
+            vendor_path: Some("/usr/lib/systemd/system/a.service".to_string()),
+            fragment_path: "/usr/lib/systemd/system/a.service".to_string(),
+            ..UnitDelta::default()
+        };
+        assert!(!vendor.is_customized());
+
+        let overridden = UnitDelta { fragment_path: "/etc/systemd/system/a.service".to_string(), ..vendor.clone() };
+        assert!(overridden.is_customized());
+
+        let extended = UnitDelta {
+            drop_in_paths: vec!["/etc/systemd/system/a.service.d/override.conf".to_string()],
+            ..vendor.clone()
+        };
+        assert!(extended.is_customized());
+
+        let local = UnitDelta { vendor_path: None, ..vendor };
+        assert!(local.is_customized());
+    }
+}
@@ -0,0 +1,225 @@
+const UNIT: &[&str] = &[
+    "Description", "Documentation", "Wants", "Requires", "Requisite", "BindsTo", "PartOf", "Upholds",
+    "Conflicts", "Before", "After", "OnFailure", "OnSuccess", "PropagatesReloadTo", "ReloadPropagatedFrom",
+    "PropagatesStopTo", "StopPropagatedFrom", "JoinsNamespaceOf", "RequiresMountsFor", "WantsMountsFor",
+    "OnFailureJobMode", "IgnoreOnIsolate", "StopWhenUnneeded", "RefuseManualStart", "RefuseManualStop",
+    "AllowIsolate", "DefaultDependencies", "SurviveFinalKillSignal", "CollectMode", "FailureAction",
+    "SuccessAction", "FailureActionExitStatus", "SuccessActionExitStatus", "JobTimeoutSec",
+    "JobRunningTimeoutSec", "JobTimeoutAction", "JobTimeoutRebootArgument", "StartLimitIntervalSec",
+    "StartLimitBurst", "StartLimitAction", "RebootArgument", "SourcePath", "ConditionArchitecture",
+    "ConditionFirmware", "ConditionVirtualization", "ConditionHost", "ConditionKernelCommandLine",
+    "ConditionKernelVersion", "ConditionCredential", "ConditionEnvironment", "ConditionSecurity",
+    "ConditionCapability", "ConditionACPower", "ConditionNeedsUpdate", "ConditionFirstBoot",
+    "ConditionPathExists", "ConditionPathExistsGlob", "ConditionPathIsDirectory", "ConditionPathIsSymbolicLink",
+    "ConditionPathIsMountPoint", "ConditionPathIsReadWrite", "ConditionPathIsEncrypted",
+    "ConditionDirectoryNotEmpty", "ConditionFileNotEmpty", "ConditionFileIsExecutable", "ConditionUser",
+    "ConditionGroup", "ConditionControlGroupController", "ConditionMemory", "ConditionCPUs",
+    "ConditionCPUFeature", "ConditionOSRelease", "ConditionMemoryPressure", "ConditionCPUPressure",
+    "ConditionIOPressure", "AssertArchitecture", "AssertVirtualization", "AssertHost",
+    "AssertKernelCommandLine", "AssertKernelVersion", "AssertCredential", "AssertEnvironment",
+    "AssertSecurity", "AssertCapability", "AssertACPower", "AssertNeedsUpdate", "AssertFirstBoot",
+    "AssertPathExists", "AssertPathExistsGlob", "AssertPathIsDirectory", "AssertPathIsSymbolicLink",
+    "AssertPathIsMountPoint", "AssertPathIsReadWrite", "AssertPathIsEncrypted", "AssertDirectoryNotEmpty",
+    "AssertFileNotEmpty", "AssertFileIsExecutable", "AssertUser", "AssertGroup",
+    "AssertControlGroupController", "AssertMemory", "AssertCPUs", "AssertCPUFeature", "AssertOSRelease",
+];
+
+const INSTALL: &[&str] = &["Alias", "WantedBy", "RequiredBy", "UpheldBy", "Also", "DefaultInstance"];
+
+const SERVICE: &[&str] = &[
+    "Type", "ExitType", "RemainAfterExit", "GuessMainPID", "PIDFile", "BusName", "ExecStart", "ExecStartPre",
+    "ExecStartPost", "ExecCondition", "ExecReload", "ExecStop", "ExecStopPost", "RestartSec",
+    "RestartSteps", "RestartMaxDelaySec", "TimeoutStartSec", "TimeoutStopSec", "TimeoutAbortSec",
+    "TimeoutSec", "TimeoutStartFailureMode", "TimeoutStopFailureMode", "RuntimeMaxSec",
+    "RuntimeRandomizedExtraSec", "WatchdogSec", "Restart", "RestartMode", "SuccessExitStatus",
+    "RestartPreventExitStatus", "RestartForceExitStatus", "RootDirectoryStartOnly", "NonBlocking",
+    "NotifyAccess", "Sockets", "FileDescriptorStoreMax", "FileDescriptorStorePreserve", "USBFunctionDescriptors",
+    "USBFunctionStrings", "OOMPolicy", "OpenFile", "ReloadSignal",
+];
+
+const SOCKET: &[&str] = &[
+    "ListenStream", "ListenDatagram", "ListenSequentialPacket", "ListenFIFO", "ListenSpecial", "ListenNetlink",
+    "ListenMessageQueue", "ListenUSBFunction", "SocketProtocol", "BindIPv6Only", "Backlog", "BindToDevice",
+    "SocketUser", "SocketGroup", "DirectoryMode", "SocketMode", "Accept", "Writable", "FlushPending",
+    "MaxConnections", "MaxConnectionsPerSource", "KeepAlive", "KeepAliveTimeSec", "KeepAliveIntervalSec",
+    "KeepAliveProbes", "NoDelay", "Priority", "DeferAcceptSec", "ReceiveBuffer", "SendBuffer",
+    "IPTOS", "IPTTL", "Mark", "ReusePort", "SmackLabel", "SmackLabelIPIn", "SmackLabelIPOut", "SELinuxContextFromNet",
+    "PipeSize", "MessageQueueMaxMessages", "MessageQueueMessageSize", "FreeBind", "Transparent", "Broadcast",
+    "PassCredentials", "PassSecurity", "PassPacketInfo", "Timestamping", "TCPCongestion", "ExecStartPre",
+    "ExecStartPost", "ExecStopPre", "ExecStopPost", "TimeoutSec", "Service", "RemoveOnStop", "Symlinks",
+    "FileDescriptorName", "TriggerLimitIntervalSec", "TriggerLimitBurst", "PollLimitIntervalSec",
+    "PollLimitBurst", "PassFileDescriptorsToExec",
+];
+
+const TIMER: &[&str] = &[
+    "OnActiveSec", "OnBootSec", "OnStartupSec", "OnUnitActiveSec", "OnUnitInactiveSec", "OnCalendar",
+    "AccuracySec", "RandomizedDelaySec", "RandomizedOffsetSec", "FixedRandomDelay", "OnClockChange",
+    "OnTimezoneChange", "Unit", "Persistent", "WakeSystem", "RemainAfterElapse", "DeferReactivation",
+];
+
+const PATH: &[&str] = &[
+    "PathExists", "PathExistsGlob", "PathChanged", "PathModified", "DirectoryNotEmpty", "Unit", "MakeDirectory",
+    "DirectoryMode", "TriggerLimitIntervalSec", "TriggerLimitBurst",
+];
+
+const MOUNT: &[&str] = &[
+    "What", "Where", "Type", "Options", "SloppyOptions", "LazyUnmount", "ReadWriteOnly", "ForceUnmount",
+    "DirectoryMode", "TimeoutSec",
+];
+
+const AUTOMOUNT: &[&str] = &["Where", "ExtraOptions", "DirectoryMode", "TimeoutIdleSec"];
+
+const SWAP: &[&str] = &["What", "Priority", "Options", "TimeoutSec"];
+
+const SCOPE: &[&str] = &["OOMPolicy", "RuntimeMaxSec", "RuntimeRandomizedExtraSec"];
+
+const EXEC: &[&str] = &[
+    "ExecSearchPath", "WorkingDirectory", "RootDirectory", "RootImage", "RootImageOptions", "RootEphemeral",
+    "RootHash", "RootHashSignature", "RootVerity", "RootImagePolicy", "MountImagePolicy",
+    "ExtensionImagePolicy", "MountAPIVFS", "ProtectProc", "ProcSubset", "BindPaths", "BindReadOnlyPaths",
+    "MountImages", "ExtensionImages", "ExtensionDirectories", "User", "Group", "DynamicUser",
+    "SupplementaryGroups", "SetLoginEnvironment", "PAMName", "CapabilityBoundingSet", "AmbientCapabilities",
+    "NoNewPrivileges", "SecureBits", "SELinuxContext", "AppArmorProfile", "SmackProcessLabel", "LimitCPU",
+    "LimitFSIZE", "LimitDATA", "LimitSTACK", "LimitCORE", "LimitRSS", "LimitNOFILE", "LimitAS", "LimitNPROC",
+    "LimitMEMLOCK", "LimitLOCKS", "LimitSIGPENDING", "LimitMSGQUEUE", "LimitNICE", "LimitRTPRIO",
+    "LimitRTTIME", "UMask", "CoredumpFilter", "KeyringMode", "OOMScoreAdjust", "TimerSlackNSec",
+    "Personality", "IgnoreSIGPIPE", "Nice", "CPUSchedulingPolicy", "CPUSchedulingPriority",
+    "CPUSchedulingResetOnFork", "CPUAffinity", "NUMAPolicy", "NUMAMask", "IOSchedulingClass",
+    "IOSchedulingPriority", "ProtectSystem", "ProtectHome", "RuntimeDirectory", "StateDirectory",
+    "CacheDirectory", "LogsDirectory", "ConfigurationDirectory", "RuntimeDirectoryMode", "StateDirectoryMode",
+    "CacheDirectoryMode", "LogsDirectoryMode", "ConfigurationDirectoryMode", "RuntimeDirectoryPreserve",
+    "TimeoutCleanSec", "ReadWritePaths", "ReadOnlyPaths", "InaccessiblePaths", "ExecPaths", "NoExecPaths",
+    "TemporaryFileSystem", "PrivateTmp", "PrivateDevices", "PrivateNetwork", "NetworkNamespacePath",
+    "PrivateIPC", "IPCNamespacePath", "MemoryKSM", "PrivateUsers", "ProtectHostname", "ProtectClock",
+    "ProtectKernelTunables", "ProtectKernelModules", "ProtectKernelLogs", "ProtectControlGroups",
+    "RestrictAddressFamilies", "RestrictFileSystems", "RestrictNamespaces", "LockPersonality",
+    "MemoryDenyWriteExecute", "RestrictRealtime", "RestrictSUIDSGID", "RemoveIPC", "PrivateMounts",
+    "MountFlags", "SystemCallFilter", "SystemCallErrorNumber", "SystemCallArchitectures", "SystemCallLog",
+    "Environment", "EnvironmentFile", "PassEnvironment", "UnsetEnvironment", "StandardInput",
+    "StandardOutput", "StandardError", "StandardInputText", "StandardInputData", "LogLevelMax",
+    "LogExtraFields", "LogRateLimitIntervalSec", "LogRateLimitBurst", "LogFilterPatterns", "LogNamespace",
+    "SyslogIdentifier", "SyslogFacility", "SyslogLevel", "SyslogLevelPrefix", "TTYPath", "TTYReset",
+    "TTYVHangup", "TTYRows", "TTYColumns", "TTYVTDisallocate", "LoadCredential", "LoadCredentialEncrypted",
+    "ImportCredential", "SetCredential", "SetCredentialEncrypted", "UtmpIdentifier", "UtmpMode",
+];
+
+const KILL: &[&str] = &[
+    "KillMode", "KillSignal", "RestartKillSignal", "SendSIGHUP", "SendSIGKILL", "FinalKillSignal",
+    "WatchdogSignal",
+];
+
+const RESOURCE_CONTROL: &[&str] = &[
+    "CPUAccounting", "CPUWeight", "StartupCPUWeight", "CPUQuota", "CPUQuotaPeriodSec", "AllowedCPUs",
+    "StartupAllowedCPUs", "AllowedMemoryNodes", "StartupAllowedMemoryNodes", "MemoryAccounting", "MemoryMin",
+    "MemoryLow", "StartupMemoryLow", "DefaultStartupMemoryLow", "MemoryHigh", "StartupMemoryHigh", "MemoryMax",
+    "StartupMemoryMax", "MemorySwapMax", "StartupMemorySwapMax", "MemoryZSwapMax", "StartupMemoryZSwapMax",
+    "MemoryZSwapWriteback", "TasksAccounting", "TasksMax", "IOAccounting", "IOWeight", "StartupIOWeight",
+    "IODeviceWeight", "IOReadBandwidthMax", "IOWriteBandwidthMax", "IOReadIOPSMax", "IOWriteIOPSMax",
+    "IODeviceLatencyTargetSec", "IPAccounting", "IPAddressAllow", "IPAddressDeny", "SocketBindAllow",
+    "SocketBindDeny", "RestrictNetworkInterfaces", "NFTSet", "IPIngressFilterPath", "IPEgressFilterPath",
+    "BPFProgram", "DeviceAllow", "DevicePolicy", "Slice", "Delegate", "DelegateSubgroup", "DisableControllers",
+    "ManagedOOMSwap", "ManagedOOMMemoryPressure", "ManagedOOMMemoryPressureLimit",
+    "ManagedOOMMemoryPressureDurationSec", "ManagedOOMPreference", "MemoryPressureWatch",
+    "MemoryPressureThresholdSec", "CoredumpReceive", "CPUShares", "StartupCPUShares", "MemoryLimit",
+    "BlockIOAccounting", "BlockIOWeight", "StartupBlockIOWeight", "BlockIODeviceWeight",
+    "BlockIOReadBandwidth", "BlockIOWriteBandwidth",
+];
+
+/// Directive groups accepted in a section, `None` when the section is unknown.
+fn section_directives(section: &str) -> Option<Vec<&'static [&'static str]>> {
+    let groups: Vec<&'static [&'static str]> = match section {
+        "Unit" => vec![UNIT],
+        "Install" => vec![INSTALL],
+        "Service" => vec![SERVICE, EXEC, KILL, RESOURCE_CONTROL],
+        "Socket" => vec![SOCKET, EXEC, KILL, RESOURCE_CONTROL],
+        "Mount" => vec![MOUNT, EXEC, KILL, RESOURCE_CONTROL],
+        "Swap" => vec![SWAP, EXEC, KILL, RESOURCE_CONTROL],
+        "Scope" => vec![SCOPE, KILL, RESOURCE_CONTROL],
+        "Slice" => vec![RESOURCE_CONTROL],
+        "Timer" => vec![TIMER],
+        "Path" => vec![PATH],
+        "Automount" => vec![AUTOMOUNT],
+        _ => return None,
+    };
+    Some(groups)
+}
+
+/// Section that holds the type specific settings of a unit name, e.g. `Service` for `foo.service`.
+pub fn type_section(unit_name: &str) -> Option<&'static str> {
+    match unit_name.rsplit('.').next()? {
+        "service" => Some("Service"),
+        "socket" => Some("Socket"),
+        "mount" => Some("Mount"),
+        "swap" => Some("Swap"),
+        "scope" => Some("Scope"),
+        "slice" => Some("Slice"),
+        "timer" => Some("Timer"),
+        "path" => Some("Path"),
+        "automount" => Some("Automount"),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    /// Zero based line index, `None` when the message is not tied to a line.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Checks section and directive names of a unit file against the known directive list.
+pub fn validate_unit_file(unit_name: &str, content: &str) -> Vec<Diagnostic> {
+    let own_section = type_section(unit_name);
+    let mut diagnostics = Vec::new();
+    let mut section: Option<String> = None;
+
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) else {
+                diagnostics.push(Diagnostic { line: Some(index), message: "Unterminated section header".to_string() });
+                section = None;
+                continue;
+            };
+            if !name.starts_with("X-") {
+                let known = section_directives(name).is_some();
+                let allowed = matches!(name, "Unit" | "Install") || Some(name) == own_section;
+                if !known || !allowed {
+                    diagnostics.push(Diagnostic {
+                        line: Some(index),
+                        message: format!("Unknown section '{name}' for {unit_name}"),
+                    });
+                }
+            }
+            section = Some(name.to_string());
+            continue;
+        }
+
+        let Some((key, _)) = line.split_once('=') else {
+            diagnostics.push(Diagnostic { line: Some(index), message: "Missing '=' in assignment".to_string() });
+            continue;
+        };
+        let key = key.trim();
+
+        let Some(section) = &section else {
+            diagnostics.push(Diagnostic { line: Some(index), message: format!("'{key}' is outside of any section") });
+            continue;
+        };
+        if key.starts_with("X-") || section.starts_with("X-") {
+            continue;
+        }
+        if let Some(groups) = section_directives(section)
+            && !groups.iter().any(|group| group.contains(&key)) {
+                diagnostics.push(Diagnostic {
+                    line: Some(index),
+                    message: format!("Unknown directive '{key}' in section [{section}]"),
+                });
+        }
+    }
+
+    diagnostics
+}
@@ -0,0 +1,65 @@
+/// Manager methods that queue a job for a unit.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum JobOperation {
+    Start,
+    Stop,
+    Restart,
+    Reload,
+    ReloadOrRestart,
+    TryRestart,
+    ReloadOrTryRestart,
+}
+
+/// How the manager merges a new job with the jobs already queued.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum JobMode {
+    /// Replace conflicting queued jobs.
+    #[default]
+    Replace,
+    /// Fail if the job conflicts with a queued one.
+    Fail,
+    /// Stop every unit the job does not depend on.
+    Isolate,
+    /// Ignore all unit dependencies of the job.
+    IgnoreDependencies,
+    /// Only honour ordering dependencies, ignoring requirements.
+    IgnoreRequirements,
+}
+
+impl JobMode {
+    /// Modes the unit list cycles through. Isolate stops every other unit, so it is only offered
+    /// by the targets view, behind its typed confirmation.
+    pub const ALL: [JobMode; 4] = [
+        JobMode::Replace,
+        JobMode::Fail,
+        JobMode::IgnoreDependencies,
+        JobMode::IgnoreRequirements,
+    ];
+
+    /// The mode argument of `StartUnit` and the other job methods.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobMode::Replace => "replace",
+            JobMode::Fail => "fail",
+            JobMode::Isolate => "isolate",
+            JobMode::IgnoreDependencies => "ignore-dependencies",
+            JobMode::IgnoreRequirements => "ignore-requirements",
+        }
+    }
+
+    pub fn next(self) -> JobMode {
+        let index = JobMode::ALL.iter().position(|mode| *mode == self).unwrap_or(0);
+        JobMode::ALL[(index + 1) % JobMode::ALL.len()]
+    }
+}
+
+/// A job in the manager's queue, as returned by `ListJobs`.
+#[derive(Clone, Debug)]
+pub struct UnitJob {
+    pub id: u32,
+    pub unit: String,
+    /// start, stop, restart, reload, verify-active…
+    pub job_type: String,
+    /// waiting or running
+    pub state: String,
+}
@@ -0,0 +1,36 @@
+#[derive(Clone, Debug, Default)]
+pub struct UnitProcess {
+    pub pid: u32,
+    pub ppid: u32,
+    pub cgroup: String,
+    pub command: String,
+    pub user: String,
+    /// User plus system time in clock ticks.
+    pub cpu_ticks: u64,
+    pub rss_bytes: u64,
+}
+
+/// Orders processes as a depth first walk of the parent/child tree, returning each with its depth.
+pub fn process_tree(processes: &[UnitProcess]) -> Vec<(usize, &UnitProcess)> {
+    fn walk<'a>(
+        parent: u32,
+        depth: usize,
+        sorted: &[&'a UnitProcess],
+        output: &mut Vec<(usize, &'a UnitProcess)>,
+    ) {
+        for child in sorted.iter().filter(|p| p.ppid == parent && p.pid != parent) {
+            output.push((depth, child));
+            walk(child.pid, depth + 1, sorted, output);
+        }
+    }
+
+    let mut sorted: Vec<&UnitProcess> = processes.iter().collect();
+    sorted.sort_by_key(|p| p.pid);
+
+    let mut output = Vec::with_capacity(sorted.len());
+    for root in sorted.iter().filter(|p| !sorted.iter().any(|parent| parent.pid == p.ppid)) {
+        output.push((0, *root));
+        walk(root.pid, 1, &sorted, &mut output);
+    }
+    output
+}
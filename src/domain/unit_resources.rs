@@ -0,0 +1,89 @@
+use super::transient_unit::{parse_bytes, parse_cpu_quota};
+
+/// Resource-control settings that can be changed on a running unit.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ResourceControl {
+    MemoryMax,
+    MemoryHigh,
+    CpuQuota,
+    CpuWeight,
+    TasksMax,
+    IoWeight,
+}
+
+impl ResourceControl {
+    pub const ALL: [ResourceControl; 6] = [
+        ResourceControl::MemoryMax,
+        ResourceControl::MemoryHigh,
+        ResourceControl::CpuQuota,
+        ResourceControl::CpuWeight,
+        ResourceControl::TasksMax,
+        ResourceControl::IoWeight,
+    ];
+
+    /// Name of the directive in unit files.
+    pub fn directive(self) -> &'static str {
+        match self {
+            ResourceControl::MemoryMax => "MemoryMax",
+            ResourceControl::MemoryHigh => "MemoryHigh",
+            ResourceControl::CpuQuota => "CPUQuota",
+            ResourceControl::CpuWeight => "CPUWeight",
+            ResourceControl::TasksMax => "TasksMax",
+            ResourceControl::IoWeight => "IOWeight",
+        }
+    }
+
+    /// Name of the D-Bus property, which differs from the directive for `CPUQuota=`.
+    pub fn property(self) -> &'static str {
+        match self {
+            ResourceControl::CpuQuota => "CPUQuotaPerSecUSec",
+            _ => self.directive(),
+        }
+    }
+
+    pub fn hint(self) -> &'static str {
+        match self {
+            ResourceControl::MemoryMax | ResourceControl::MemoryHigh => "bytes with K/M/G/T, or infinity",
+            ResourceControl::CpuQuota => "percentage such as 50%, or infinity",
+            ResourceControl::CpuWeight | ResourceControl::IoWeight => "1-10000, empty for the default",
+            ResourceControl::TasksMax => "number of tasks, or infinity",
+        }
+    }
+
+    /// Parses a value as typed in the dialog. `u64::MAX` stands for "infinity" or "not set", as systemd reports it.
+    pub fn parse(self, value: &str) -> Result<u64, String> {
+        let value = value.trim();
+        let unset = value.is_empty() || value == "infinity";
+        let parsed = match self {
+            ResourceControl::MemoryMax | ResourceControl::MemoryHigh if unset => Some(u64::MAX),
+            ResourceControl::MemoryMax | ResourceControl::MemoryHigh => parse_bytes(value),
+            ResourceControl::CpuQuota if unset => Some(u64::MAX),
+            ResourceControl::CpuQuota => parse_cpu_quota(value),
+            ResourceControl::TasksMax if unset => Some(u64::MAX),
+            ResourceControl::TasksMax => value.parse().ok(),
+            ResourceControl::CpuWeight | ResourceControl::IoWeight if value.is_empty() => Some(u64::MAX),
+            ResourceControl::CpuWeight | ResourceControl::IoWeight => {
+                value.parse().ok().filter(|weight| (1..=10_000).contains(weight))
+            }
+        };
+        parsed.ok_or_else(|| format!("Invalid {} '{value}', expected {}", self.directive(), self.hint()))
+    }
+
+    /// Formats a value the way `parse` reads it back.
+    pub fn format(self, value: u64) -> String {
+        match self {
+            ResourceControl::CpuWeight | ResourceControl::IoWeight if value == u64::MAX => String::new(),
+            _ if value == u64::MAX => "infinity".to_string(),
+            ResourceControl::MemoryMax | ResourceControl::MemoryHigh => {
+                // Only exact multiples get a suffix so the value parses back unchanged.
+                const SUFFIXES: [(u64, &str); 4] = [(1 << 40, "T"), (1 << 30, "G"), (1 << 20, "M"), (1 << 10, "K")];
+                SUFFIXES
+                    .iter()
+                    .find(|(size, _)| value > 0 && value.is_multiple_of(*size))
+                    .map_or_else(|| value.to_string(), |(size, suffix)| format!("{}{suffix}", value / size))
+            }
+            ResourceControl::CpuQuota => format!("{}%", value / 10_000),
+            ResourceControl::CpuWeight | ResourceControl::TasksMax | ResourceControl::IoWeight => value.to_string(),
+        }
+    }
+}
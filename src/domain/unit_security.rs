@@ -0,0 +1,295 @@
+/// Sandboxing settings of a service, as exposed by the `org.freedesktop.systemd1.Service` interface.
+#[derive(Clone, Debug, Default)]
+pub struct SecuritySettings {
+    pub user: String,
+    pub dynamic_user: bool,
+    pub no_new_privileges: bool,
+    pub private_tmp: bool,
+    pub private_devices: bool,
+    pub private_network: bool,
+    pub private_users: bool,
+    pub private_mounts: bool,
+    pub protect_system: String,
+    pub protect_home: String,
+    pub protect_proc: String,
+    pub protect_kernel_tunables: bool,
+    pub protect_kernel_modules: bool,
+    pub protect_kernel_logs: bool,
+    pub protect_control_groups: bool,
+    pub protect_clock: bool,
+    pub protect_hostname: bool,
+    pub restrict_suid_sgid: bool,
+    pub restrict_realtime: bool,
+    pub lock_personality: bool,
+    pub memory_deny_write_execute: bool,
+    pub remove_ipc: bool,
+    pub device_policy: String,
+    /// Namespace types the service may still create, as `CLONE_NEW*` flags.
+    pub restrict_namespaces: u64,
+    pub capability_bounding_set: u64,
+    /// `SystemCallFilter=`: whether the list is an allow list, and its entries.
+    pub system_call_filter: (bool, Vec<String>),
+    pub system_call_architectures: Vec<String>,
+    /// `RestrictAddressFamilies=`: whether the list is an allow list, and its entries.
+    pub restrict_address_families: (bool, Vec<String>),
+}
+
+#[derive(Clone, Debug)]
+pub struct SecurityCheck {
+    pub directive: &'static str,
+    pub description: &'static str,
+    pub weight: u32,
+    /// From 0 (fully restricted) to 10 (not restricted at all).
+    pub badness: u32,
+}
+
+impl SecurityCheck {
+    pub fn passed(&self) -> bool {
+        self.badness == 0
+    }
+
+    /// Share of the total exposure caused by this check, on the same 0.0 to 10.0 scale.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn exposure(&self, total_weight: u32) -> f64 {
+        if total_weight == 0 {
+            return 0.0;
+        }
+        f64::from(self.weight * self.badness) / f64::from(total_weight)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SecurityRating {
+    Perfect,
+    Safe,
+    Ok,
+    Medium,
+    Exposed,
+    Unsafe,
+    Dangerous,
+}
+
+impl SecurityRating {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SecurityRating::Perfect => "PERFECT",
+            SecurityRating::Safe => "SAFE",
+            SecurityRating::Ok => "OK",
+            SecurityRating::Medium => "MEDIUM",
+            SecurityRating::Exposed => "EXPOSED",
+            SecurityRating::Unsafe => "UNSAFE",
+            SecurityRating::Dangerous => "DANGEROUS",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SecurityReport {
+    pub checks: Vec<SecurityCheck>,
+}
+
+impl SecurityReport {
+    pub fn total_weight(&self) -> u32 {
+        self.checks.iter().map(|check| check.weight).sum()
+    }
+
+    /// Overall exposure from 0.0 (locked down) to 10.0 (unrestricted), like `systemd-analyze security`.
+    pub fn exposure(&self) -> f64 {
+        let total_weight = self.total_weight();
+        let exposure: f64 = self.checks.iter().map(|check| check.exposure(total_weight)).sum();
+        (exposure * 10.0).round() / 10.0
+    }
+
+    pub fn rating(&self) -> SecurityRating {
+        let exposure = self.exposure();
+        if exposure >= 10.0 {
+            SecurityRating::Dangerous
+        } else if exposure >= 9.0 {
+            SecurityRating::Unsafe
+        } else if exposure >= 7.5 {
+            SecurityRating::Exposed
+        } else if exposure >= 5.0 {
+            SecurityRating::Medium
+        } else if exposure >= 1.0 {
+            SecurityRating::Ok
+        } else if exposure >= 0.1 {
+            SecurityRating::Safe
+        } else {
+            SecurityRating::Perfect
+        }
+    }
+}
+
+const CLONE_NEWNS: u64 = 0x0002_0000;
+const CLONE_NEWCGROUP: u64 = 0x0200_0000;
+const CLONE_NEWUTS: u64 = 0x0400_0000;
+const CLONE_NEWIPC: u64 = 0x0800_0000;
+const CLONE_NEWUSER: u64 = 0x1000_0000;
+const CLONE_NEWPID: u64 = 0x2000_0000;
+const CLONE_NEWNET: u64 = 0x4000_0000;
+
+const CAP_CHOWN: u32 = 0;
+const CAP_DAC_OVERRIDE: u32 = 1;
+const CAP_DAC_READ_SEARCH: u32 = 2;
+const CAP_FOWNER: u32 = 3;
+const CAP_KILL: u32 = 5;
+const CAP_SETGID: u32 = 6;
+const CAP_SETUID: u32 = 7;
+const CAP_SETPCAP: u32 = 8;
+const CAP_NET_ADMIN: u32 = 12;
+const CAP_NET_RAW: u32 = 13;
+const CAP_IPC_LOCK: u32 = 14;
+const CAP_SYS_MODULE: u32 = 16;
+const CAP_SYS_RAWIO: u32 = 17;
+const CAP_SYS_PTRACE: u32 = 19;
+const CAP_SYS_ADMIN: u32 = 21;
+const CAP_SYS_BOOT: u32 = 22;
+const CAP_SYS_TIME: u32 = 25;
+const CAP_MKNOD: u32 = 27;
+const CAP_SYSLOG: u32 = 34;
+const CAP_BPF: u32 = 39;
+
+/// (directive, capabilities, weight, description)
+const CAPABILITY_CHECKS: [(&str, &[u32], u32, &str); 12] = [
+    ("CapabilityBoundingSet=~CAP_SYS_ADMIN", &[CAP_SYS_ADMIN], 1500, "Service may have administrator privileges"),
+    ("CapabilityBoundingSet=~CAP_SET(UID|GID|PCAP)", &[CAP_SETUID, CAP_SETGID, CAP_SETPCAP], 1500, "Service may change UID/GID identities or capabilities"),
+    ("CapabilityBoundingSet=~CAP_SYS_PTRACE", &[CAP_SYS_PTRACE], 1500, "Service may trace and inspect other processes"),
+    ("CapabilityBoundingSet=~CAP_NET_ADMIN", &[CAP_NET_ADMIN], 1500, "Service may reconfigure the network"),
+    ("CapabilityBoundingSet=~CAP_SYS_MODULE", &[CAP_SYS_MODULE], 1500, "Service may load kernel modules"),
+    ("CapabilityBoundingSet=~CAP_DAC_*", &[CAP_DAC_OVERRIDE, CAP_DAC_READ_SEARCH], 1500, "Service may override file access permissions"),
+    ("CapabilityBoundingSet=~CAP_BPF", &[CAP_BPF], 1500, "Service may load BPF programs"),
+    ("CapabilityBoundingSet=~CAP_SYS_RAWIO", &[CAP_SYS_RAWIO], 1000, "Service may issue raw I/O operations"),
+    ("CapabilityBoundingSet=~CAP_SYS_TIME", &[CAP_SYS_TIME], 1000, "Service may change the system clock"),
+    ("CapabilityBoundingSet=~CAP_(CHOWN|FOWNER|MKNOD)", &[CAP_CHOWN, CAP_FOWNER, CAP_MKNOD], 1000, "Service may change file ownership or create device nodes"),
+    ("CapabilityBoundingSet=~CAP_SYSLOG", &[CAP_SYSLOG], 1000, "Service may read the kernel log"),
+    ("CapabilityBoundingSet=~CAP_(KILL|NET_RAW|IPC_LOCK|SYS_BOOT)", &[CAP_KILL, CAP_NET_RAW, CAP_IPC_LOCK, CAP_SYS_BOOT], 500, "Service may signal other users, use raw sockets, lock memory or reboot"),
+];
+
+/// (directive, namespace flag, weight, description)
+const NAMESPACE_CHECKS: [(&str, u64, u32, &str); 7] = [
+    ("RestrictNamespaces=~user", CLONE_NEWUSER, 1500, "Service may create user namespaces"),
+    ("RestrictNamespaces=~mnt", CLONE_NEWNS, 500, "Service may create file system namespaces"),
+    ("RestrictNamespaces=~ipc", CLONE_NEWIPC, 500, "Service may create IPC namespaces"),
+    ("RestrictNamespaces=~pid", CLONE_NEWPID, 500, "Service may create process namespaces"),
+    ("RestrictNamespaces=~cgroup", CLONE_NEWCGROUP, 500, "Service may create cgroup namespaces"),
+    ("RestrictNamespaces=~uts", CLONE_NEWUTS, 500, "Service may create hostname namespaces"),
+    ("RestrictNamespaces=~net", CLONE_NEWNET, 500, "Service may create network namespaces"),
+];
+
+fn check(directive: &'static str, weight: u32, restricted: bool, description: &'static str) -> SecurityCheck {
+    SecurityCheck { directive, description, weight, badness: if restricted { 0 } else { 10 } }
+}
+
+fn allows_family(settings: &SecuritySettings, families: &[&str]) -> bool {
+    let (allow_list, entries) = &settings.restrict_address_families;
+    let listed = entries.iter().any(|family| families.contains(&family.as_str()));
+    if *allow_list { listed } else { !listed }
+}
+
+/// Scores the sandboxing settings the way `systemd-analyze security` does, with a subset of its checks.
+pub fn evaluate(settings: &SecuritySettings) -> SecurityReport {
+    let runs_as_root = !settings.dynamic_user && (settings.user.is_empty() || settings.user == "root" || settings.user == "0");
+
+    let protect_system = match settings.protect_system.as_str() {
+        "strict" => 0,
+        "full" => 3,
+        "yes" | "true" => 5,
+        _ => 10,
+    };
+    let protect_home = match settings.protect_home.as_str() {
+        "yes" | "true" => 0,
+        "tmpfs" => 1,
+        "read-only" => 5,
+        _ => 10,
+    };
+    let protect_proc = match settings.protect_proc.as_str() {
+        "noaccess" | "invisible" => 0,
+        "ptraceable" => 5,
+        _ => 10,
+    };
+
+    let mut checks = vec![
+        check("User=/DynamicUser=", 2000, !runs_as_root, "Service runs as root"),
+        check("NoNewPrivileges=", 1000, settings.no_new_privileges, "Service processes may acquire new privileges"),
+        check("PrivateNetwork=", 2500, settings.private_network, "Service has access to the host's network"),
+        check("PrivateTmp=", 1000, settings.private_tmp, "Service has access to other software's temporary files"),
+        check("PrivateDevices=", 1000, settings.private_devices, "Service potentially has access to hardware devices"),
+        check("PrivateUsers=", 1000, settings.private_users, "Service has access to other users"),
+        check("PrivateMounts=", 1000, settings.private_mounts, "Service may install system mounts"),
+        SecurityCheck {
+            directive: "ProtectSystem=",
+            description: "Service has write access to the OS file hierarchy",
+            weight: 1000,
+            badness: protect_system,
+        },
+        SecurityCheck {
+            directive: "ProtectHome=",
+            description: "Service has access to home directories",
+            weight: 1000,
+            badness: protect_home,
+        },
+        SecurityCheck {
+            directive: "ProtectProc=",
+            description: "Service has full access to the process tree in /proc",
+            weight: 1000,
+            badness: protect_proc,
+        },
+        check("ProtectKernelTunables=", 1000, settings.protect_kernel_tunables, "Service may alter kernel tunables"),
+        check("ProtectKernelModules=", 1000, settings.protect_kernel_modules, "Service may load or read kernel modules"),
+        check("ProtectKernelLogs=", 1000, settings.protect_kernel_logs, "Service may read from or write to the kernel log ring buffer"),
+        check("ProtectControlGroups=", 1000, settings.protect_control_groups, "Service may modify the control group file system"),
+        check("ProtectClock=", 1000, settings.protect_clock, "Service may write to the hardware clock or system clock"),
+        check("ProtectHostname=", 50, settings.protect_hostname, "Service may change the system host name"),
+        check(
+            "DevicePolicy=",
+            1000,
+            settings.private_devices || matches!(settings.device_policy.as_str(), "closed" | "strict"),
+            "Service has no device ACL",
+        ),
+        check("RestrictSUIDSGID=", 1000, settings.restrict_suid_sgid, "Service may create SUID/SGID files"),
+        check("RestrictRealtime=", 500, settings.restrict_realtime, "Service may acquire realtime scheduling"),
+        check("LockPersonality=", 100, settings.lock_personality, "Service may change the ABI personality"),
+        check("MemoryDenyWriteExecute=", 100, settings.memory_deny_write_execute, "Service may create writable executable memory mappings"),
+        check("RemoveIPC=", 100, settings.remove_ipc || !runs_as_root, "Service runs as root, IPC objects are not removed"),
+        check(
+            "SystemCallFilter=",
+            1000,
+            settings.system_call_filter.0 && !settings.system_call_filter.1.is_empty(),
+            "Service does not filter system calls",
+        ),
+        check(
+            "SystemCallArchitectures=",
+            200,
+            !settings.system_call_architectures.is_empty(),
+            "Service may execute system calls with all ABIs",
+        ),
+        check(
+            "RestrictAddressFamilies=~AF_(INET|INET6)",
+            1500,
+            !allows_family(settings, &["AF_INET", "AF_INET6"]),
+            "Service may allocate Internet sockets",
+        ),
+        check(
+            "RestrictAddressFamilies=~AF_PACKET",
+            1000,
+            !allows_family(settings, &["AF_PACKET"]),
+            "Service may allocate packet sockets",
+        ),
+        check(
+            "RestrictAddressFamilies=~…",
+            1250,
+            settings.restrict_address_families.0,
+            "Service may allocate exotic sockets",
+        ),
+    ];
+
+    checks.extend(NAMESPACE_CHECKS.iter().map(|(directive, flag, weight, description)| {
+        check(directive, *weight, settings.restrict_namespaces & flag == 0, description)
+    }));
+    checks.extend(CAPABILITY_CHECKS.iter().map(|(directive, capabilities, weight, description)| {
+        let granted = capabilities.iter().any(|cap| settings.capability_bounding_set & (1 << cap) != 0);
+        check(directive, *weight, !granted, description)
+    }));
+
+    SecurityReport { checks }
+}
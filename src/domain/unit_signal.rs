@@ -0,0 +1,41 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum KillTarget {
+    Main,
+    Control,
+    All,
+}
+
+impl KillTarget {
+    pub const ALL: [KillTarget; 3] = [KillTarget::Main, KillTarget::Control, KillTarget::All];
+
+    /// Value of the `whom` argument of `KillUnit`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KillTarget::Main => "main",
+            KillTarget::Control => "control",
+            KillTarget::All => "all",
+        }
+    }
+}
+
+pub const SIGNALS: [(&str, i32); 5] = [
+    ("SIGTERM", libc::SIGTERM),
+    ("SIGKILL", libc::SIGKILL),
+    ("SIGHUP", libc::SIGHUP),
+    ("SIGUSR1", libc::SIGUSR1),
+    ("SIGUSR2", libc::SIGUSR2),
+];
+
+/// Parses a signal given by number or by name, with or without the `SIG` prefix.
+pub fn parse_signal(value: &str) -> Option<i32> {
+    let value = value.trim();
+    if let Ok(number) = value.parse::<i32>() {
+        return (1..=64).contains(&number).then_some(number);
+    }
+    let name = value.to_ascii_uppercase();
+    let name = name.strip_prefix("SIG").unwrap_or(&name);
+    SIGNALS
+        .iter()
+        .find(|(signal, _)| signal.strip_prefix("SIG") == Some(name))
+        .map(|(_, number)| *number)
+}
@@ -0,0 +1,68 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Runtime state of a unit as shown by `systemctl status`.
+#[derive(Clone, Debug, Default)]
+pub struct UnitStatus {
+    pub name: String,
+    pub description: String,
+    pub load_state: String,
+    pub fragment_path: String,
+    pub unit_file_state: String,
+    pub unit_file_preset: String,
+    pub active_state: String,
+    pub sub_state: String,
+    /// Microseconds since the epoch of the last active state change, zero if unknown.
+    pub state_change_timestamp: u64,
+    pub main_pid: Option<u32>,
+    pub tasks_current: Option<u64>,
+    pub tasks_max: Option<u64>,
+    pub memory_current: Option<u64>,
+    pub cpu_usage_nsec: Option<u64>,
+    pub control_group: Option<String>,
+}
+
+/// Human friendly duration such as "3d 4h" or "12m 5s", keeping the two most significant units.
+pub fn format_duration(secs: u64) -> String {
+    let days = secs / 86400;
+    let hours = (secs % 86400) / 3600;
+    let mins = (secs % 3600) / 60;
+    let s = secs % 60;
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {mins}m")
+    } else if mins > 0 {
+        format!("{mins}m {s}s")
+    } else {
+        format!("{s}s")
+    }
+}
+
+/// Size with a binary unit suffix, as used by `systemctl status` ("12.3M").
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "K", "M", "G"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+/// Time elapsed since a timestamp in microseconds since the epoch, as returned by systemd.
+pub fn elapsed_since(timestamp_usec: u64) -> String {
+    let now_micros = u64::try_from(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros(),
+    )
+    .unwrap_or(u64::MAX);
+    format_duration(now_micros.saturating_sub(timestamp_usec) / 1_000_000)
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug, Default)]
+pub struct TargetUnit {
+    pub name: String,
+    pub description: String,
+    pub active_state: String,
+    /// Whether the target may be used with `systemctl isolate` (`AllowIsolate=yes`).
+    pub allow_isolate: bool,
+}
@@ -0,0 +1,133 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UnitKind {
+    Service,
+    Timer,
+    Socket,
+    Path,
+}
+
+impl UnitKind {
+    pub const ALL: [UnitKind; 4] = [UnitKind::Service, UnitKind::Timer, UnitKind::Socket, UnitKind::Path];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UnitKind::Service => "service",
+            UnitKind::Timer => "timer",
+            UnitKind::Socket => "socket",
+            UnitKind::Path => "path",
+        }
+    }
+
+    /// Directive that triggers the companion service, `None` for plain services.
+    pub fn trigger_directive(self) -> Option<&'static str> {
+        match self {
+            UnitKind::Service => None,
+            UnitKind::Timer => Some("OnCalendar"),
+            UnitKind::Socket => Some("ListenStream"),
+            UnitKind::Path => Some("PathChanged"),
+        }
+    }
+
+    pub fn default_install_target(self, session: bool) -> &'static str {
+        match self {
+            UnitKind::Service if session => "default.target",
+            UnitKind::Service => "multi-user.target",
+            UnitKind::Timer => "timers.target",
+            UnitKind::Socket => "sockets.target",
+            UnitKind::Path => "paths.target",
+        }
+    }
+}
+
+pub const SERVICE_TYPES: [&str; 5] = ["simple", "oneshot", "exec", "forking", "notify"];
+pub const RESTART_POLICIES: [&str; 4] = ["no", "on-failure", "on-abnormal", "always"];
+
+#[derive(Clone, Debug)]
+pub struct UnitTemplate {
+    pub kind: UnitKind,
+    pub name: String,
+    pub description: String,
+    pub exec_start: String,
+    pub trigger: String,
+    pub service_type: String,
+    pub user: String,
+    pub restart: String,
+    pub wanted_by: String,
+}
+
+impl UnitTemplate {
+    /// Name without any unit suffix the user may have typed.
+    pub fn base_name(&self) -> &str {
+        let name = self.name.trim();
+        UnitKind::ALL
+            .iter()
+            .find_map(|kind| name.strip_suffix(&format!(".{}", kind.as_str())))
+            .unwrap_or(name)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.base_name();
+        if name.is_empty() {
+            return Err("The unit name is required".to_string());
+        }
+        if name.contains(|c: char| c == '/' || c.is_whitespace()) {
+            return Err("The unit name can not contain '/' or whitespace".to_string());
+        }
+        if self.exec_start.trim().is_empty() {
+            return Err("ExecStart is required".to_string());
+        }
+        if let Some(directive) = self.kind.trigger_directive()
+            && self.trigger.trim().is_empty() {
+                return Err(format!("{directive} is required for a {} unit", self.kind.as_str()));
+        }
+        Ok(())
+    }
+
+    /// Renders the unit files as (file name, content) pairs. Timers, sockets and paths
+    /// come with the service they activate.
+    pub fn render(&self) -> Vec<(String, String)> {
+        let name = self.base_name();
+        let description = if self.description.trim().is_empty() {
+            name.to_string()
+        } else {
+            self.description.trim().to_string()
+        };
+
+        let mut service = format!("[Unit]\nDescription={description}\n\n[Service]\n");
+        service.push_str(&format!("Type={}\n", self.service_type));
+        service.push_str(&format!("ExecStart={}\n", self.exec_start.trim()));
+        if !self.user.trim().is_empty() {
+            service.push_str(&format!("User={}\n", self.user.trim()));
+        }
+        if self.restart != "no" {
+            service.push_str(&format!("Restart={}\n", self.restart));
+        }
+
+        let service_name = format!("{name}.service");
+        let Some(directive) = self.kind.trigger_directive() else {
+            if !self.wanted_by.trim().is_empty() {
+                service.push_str(&format!("\n[Install]\nWantedBy={}\n", self.wanted_by.trim()));
+            }
+            return vec![(service_name, service)];
+        };
+
+        let section = match self.kind {
+            UnitKind::Timer => "Timer",
+            UnitKind::Socket => "Socket",
+            _ => "Path",
+        };
+        let mut trigger = format!("[Unit]\nDescription={description} ({})\n\n[{section}]\n", self.kind.as_str());
+        trigger.push_str(&format!("{directive}={}\n", self.trigger.trim()));
+        if self.kind == UnitKind::Timer {
+            trigger.push_str("Persistent=true\n");
+        }
+        if !self.wanted_by.trim().is_empty() {
+            trigger.push_str(&format!("\n[Install]\nWantedBy={}\n", self.wanted_by.trim()));
+        }
+
+        vec![
+            (format!("{name}.{}", self.kind.as_str()), trigger),
+            (service_name, service),
+        ]
+    }
+}
@@ -0,0 +1,23 @@
+use zbus::blocking::{Connection, Proxy};
+use zbus::zvariant::OwnedObjectPath;
+
+/// Names of the running containers registered with systemd-machined, such as nspawn machines.
+/// Virtual machines are skipped since their bus can not be reached from here.
+pub fn running_containers() -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let connection = Connection::system()?;
+    let proxy = Proxy::new(
+        &connection,
+        "org.freedesktop.machine1",
+        "/org/freedesktop/machine1",
+        "org.freedesktop.machine1.Manager",
+    )?;
+    let machines: Vec<(String, String, String, OwnedObjectPath)> = proxy.call("ListMachines", &())?;
+
+    let mut containers: Vec<String> = machines
+        .into_iter()
+        .filter(|(_, class, ..)| class == "container")
+        .map(|(name, ..)| name)
+        .collect();
+    containers.sort();
+    Ok(containers)
+}
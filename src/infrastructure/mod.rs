@@ -1,2 +1,5 @@
 pub mod systemd_service_adapter;
 pub mod notifier;
+pub mod procfs;
+pub mod ssh_config;
+pub mod machined;
@@ -3,20 +3,54 @@ use zbus::MatchRule;
 use zbus::message::Type;
 use zbus::zvariant::{Value, OwnedValue};
 use zbus::Error;
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::thread;
 use std::time::Duration;
 
 const SLEEP_DURATION: u64 = 300;
 
-pub fn start_notifier() {
-    thread::spawn(|| {
+/// Which unit state changes raise a desktop notification, from the `[notifier]` table of the config file.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotifierRules {
+    pub enabled: bool,
+    /// Active states that are reported.
+    pub states: Vec<String>,
+    /// Unit name patterns with `*` and `?` wildcards; empty reports every unit.
+    pub units: Vec<String>,
+}
+
+impl Default for NotifierRules {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            states: vec!["failed".to_string()],
+            units: Vec::new(),
+        }
+    }
+}
+
+impl NotifierRules {
+    fn matches(&self, unit: &str, state: &str) -> bool {
+        self.states.iter().any(|known| known == state)
+            && (self.units.is_empty() || self.units.iter().any(|pattern| matches_pattern(pattern, unit)))
+    }
+}
+
+pub fn start_notifier(rules: &NotifierRules) {
+    if !rules.enabled {
+        return;
+    }
+
+    let system_rules = rules.clone();
+    thread::spawn(move || {
         let connection = match Connection::system() {
             Ok(connection) => connection,
             Err(_e) => return
         };
 
-        let notifier = match Notifier::new(connection) {
+        let notifier = match Notifier::new(connection, system_rules) {
             Ok(notifier) => notifier,
             Err(_e) => {
                 return;
@@ -27,13 +61,14 @@ pub fn start_notifier() {
         }
     });
 
-    thread::spawn(|| {
+    let session_rules = rules.clone();
+    thread::spawn(move || {
          let connection = match Connection::session() {
             Ok(connection) => connection,
             Err(_e) => return
         };
         
-        let notifier = match Notifier::new(connection) {
+        let notifier = match Notifier::new(connection, session_rules) {
             Ok(notifier) => notifier,
             Err(_e) => {
                 return;
@@ -46,13 +81,15 @@ pub fn start_notifier() {
 }
 
 pub struct Notifier {
-    connection: Connection
+    connection: Connection,
+    rules: NotifierRules,
 }
 
 impl Notifier {
-    pub fn new(connection: Connection) -> Result<Self, Error> {
+    pub fn new(connection: Connection, rules: NotifierRules) -> Result<Self, Error> {
         Ok(Self {
-            connection
+            connection,
+            rules,
         })
     }
 
@@ -91,10 +128,11 @@ impl Notifier {
 
             if let Some(state_val) = changed.get("ActiveState")
                 && let Ok(state) = <&str>::try_from(state_val) 
-                    && state == "failed" 
-                        && let Some(path) = msg.header().path() {
-                            let name = decode_unit_path(path.as_str());
-                            self.send_notification(&format!("{} {}", name, state))?;
+                    && let Some(path) = msg.header().path() {
+                        let name = decode_unit_path(path.as_str());
+                        if self.rules.matches(&name, state) {
+                            self.send_notification(&format!("{} {}", name, state), state == "failed")?;
+                        }
             }
         }
     }
@@ -102,8 +140,13 @@ impl Notifier {
     fn send_notification(
         &self,
         summary: &str,
+        failure: bool,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let body = "Systemd reported a failure";
+        let (body, icon) = if failure {
+            ("Systemd reported a failure", "dialog-error")
+        } else {
+            ("Systemd reported a state change", "dialog-information")
+        };
 
         let notification_connection = Connection::session()?;
         notification_connection.call_method(
@@ -114,7 +157,7 @@ impl Notifier {
             &(
                 "systemd-manager-tui",
                 0u32,
-                "dialog-error",
+                icon,
                 summary,
                 body,
                 Vec::<&str>::new(),
@@ -149,3 +192,71 @@ fn decode_unit_path(path: &str) -> String {
 
     out
 }
+
+/// Matches a unit name against a shell-style pattern with `*` and `?`.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name position it was tried at, to backtrack to.
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p + 1, n));
+            p += 1;
+        } else if let Some((after_star, tried)) = star {
+            p = after_star;
+            n = tried + 1;
+            star = Some((after_star, tried + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches_pattern;
+
+    #[test]
+    fn star_matches_any_run_of_characters() {
+        assert!(matches_pattern("nginx*", "nginx.service"));
+        assert!(matches_pattern("*.timer", "logrotate.timer"));
+        assert!(matches_pattern("*-*.service", "getty-tty1.service"));
+        assert!(matches_pattern("*", ""));
+        assert!(!matches_pattern("*.timer", "logrotate.service"));
+    }
+
+    #[test]
+    fn star_backtracks_past_partial_matches() {
+        assert!(matches_pattern("*ab", "aab"));
+        assert!(matches_pattern("a*b*c", "abxbxc"));
+        assert!(!matches_pattern("a*b*c", "abxbx"));
+    }
+
+    #[test]
+    fn question_mark_matches_one_character() {
+        assert!(matches_pattern("tty?.service", "tty1.service"));
+        assert!(!matches_pattern("tty?.service", "tty.service"));
+        assert!(!matches_pattern("tty?.service", "tty12.service"));
+    }
+
+    #[test]
+    fn trailing_star_matches_the_rest_or_nothing() {
+        assert!(matches_pattern("ssh*", "ssh"));
+        assert!(matches_pattern("ssh**", "sshd.service"));
+        assert!(!matches_pattern("ssh*", "openssh.service"));
+    }
+
+    #[test]
+    fn without_wildcards_the_name_has_to_be_equal() {
+        assert!(matches_pattern("sshd.service", "sshd.service"));
+        assert!(!matches_pattern("sshd.service", "sshd.socket"));
+        assert!(!matches_pattern("sshd", "sshd.service"));
+    }
+}
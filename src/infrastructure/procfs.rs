@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::domain::unit_process::UnitProcess;
+
+/// Maps user ids to names using /etc/passwd.
+pub fn read_users() -> HashMap<u32, String> {
+    fs::read_to_string("/etc/passwd")
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split(':');
+            let name = fields.next()?;
+            let uid = fields.nth(1)?.parse().ok()?;
+            Some((uid, name.to_string()))
+        })
+        .collect()
+}
+
+pub fn clock_ticks_per_second() -> u64 {
+    // SAFETY: sysconf has no preconditions
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    u64::try_from(ticks).unwrap_or(100)
+}
+
+fn page_size() -> u64 {
+    // SAFETY: sysconf has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    u64::try_from(size).unwrap_or(4096)
+}
+
+/// Fills a process with the details found in /proc, leaving defaults for anything unreadable.
+pub fn read_process(pid: u32, cgroup: String, command: String, users: &HashMap<u32, String>) -> UnitProcess {
+    let mut process = UnitProcess {
+        pid,
+        cgroup,
+        command,
+        ..UnitProcess::default()
+    };
+
+    if let Ok(stat) = fs::read_to_string(format!("/proc/{pid}/stat"))
+        && let Some((_, rest)) = stat.rsplit_once(')') {
+            // Fields after the command name, starting with the state (field 3)
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            process.ppid = fields.get(1).and_then(|v| v.parse().ok()).unwrap_or(0);
+            let utime: u64 = fields.get(11).and_then(|v| v.parse().ok()).unwrap_or(0);
+            let stime: u64 = fields.get(12).and_then(|v| v.parse().ok()).unwrap_or(0);
+            process.cpu_ticks = utime + stime;
+    }
+
+    if let Ok(statm) = fs::read_to_string(format!("/proc/{pid}/statm")) {
+        let resident: u64 = statm.split_whitespace().nth(1).and_then(|v| v.parse().ok()).unwrap_or(0);
+        process.rss_bytes = resident * page_size();
+    }
+
+    if let Ok(status) = fs::read_to_string(format!("/proc/{pid}/status"))
+        && let Some(uid) = status
+            .lines()
+            .find_map(|line| line.strip_prefix("Uid:"))
+            .and_then(|uids| uids.split_whitespace().next())
+            .and_then(|uid| uid.parse::<u32>().ok()) {
+                process.user = users.get(&uid).cloned().unwrap_or_else(|| uid.to_string());
+    }
+
+    if process.command.is_empty()
+        && let Ok(comm) = fs::read_to_string(format!("/proc/{pid}/comm")) {
+            process.command = format!("[{}]", comm.trim());
+    }
+
+    process
+}
@@ -0,0 +1,28 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// Host aliases declared in ~/.ssh/config, skipping patterns such as `*` or `!host`.
+pub fn configured_hosts() -> Vec<String> {
+    let Some(home) = std::env::var_os("HOME") else {
+        return Vec::new();
+    };
+    let content = fs::read_to_string(PathBuf::from(home).join(".ssh/config")).unwrap_or_default();
+
+    let mut hosts: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim();
+        let Some((keyword, value)) = line.split_once(|c: char| c.is_whitespace() || c == '=') else {
+            continue;
+        };
+        if !keyword.eq_ignore_ascii_case("host") {
+            continue;
+        }
+        for host in value.split_whitespace() {
+            let host = host.trim_matches(|c| c == '=' || c == '"');
+            if !host.is_empty() && !host.contains(['*', '?', '!']) && !hosts.iter().any(|known| known == host) {
+                hosts.push(host.to_string());
+            }
+        }
+    }
+    hosts
+}
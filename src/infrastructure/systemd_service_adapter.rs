@@ -1,20 +1,79 @@
 use zbus::blocking::{Connection, Proxy};
-use zbus::zvariant::{OwnedObjectPath, OwnedValue};
-use zbus::Error;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue, Value};
+use zbus::{Address, Error, MatchRule};
+use zbus::address::transport::{Transport, Unixexec};
+use zbus::blocking::MessageIterator;
+use zbus::message::Type;
 use zbus::proxy::MethodFlags;
 use std::time::Duration;
-use std::process::Command;
-use std::io::{self};
+use std::process::{Command, Stdio};
+use std::io::{self, Write};
+use std::ffi::OsString;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::thread;
+use crate::domain::boot_analysis::{BootTimestamps, UnitActivation};
 use crate::domain::service::Service;
 use crate::domain::service_repository::ServiceRepository;
 use crate::domain::service_state::ServiceState;
+use crate::domain::unit_diff::{DeltaCategory, UnitOverride};
+use crate::domain::unit_directives::Diagnostic;
+use crate::domain::transient_unit::{TransientKind, TransientUnit};
+use crate::domain::unit_job::{JobMode, UnitJob};
+use crate::domain::unit_process::UnitProcess;
+use crate::domain::unit_resources::ResourceControl;
+use crate::domain::unit_security::SecuritySettings;
+use crate::domain::unit_status::UnitStatus;
+use crate::domain::unit_target::TargetUnit;
+use crate::infrastructure::procfs;
 use rayon::prelude::*;
 use std::collections::HashMap;
 use crate::terminal::components::list::LOADING_PLACEHOLDER;
 
 const SLEEP_DURATION: u64 = 100;
 
+/// Directories of unit files shipped by packages. /run is left out: units there are runtime
+/// overrides of these, reported by `systemd-delta` as such.
+const SYSTEM_VENDOR_DIRECTORIES: [&str; 3] = [
+    "/usr/local/lib/systemd/system",
+    "/usr/lib/systemd/system",
+    "/lib/systemd/system",
+];
+const USER_VENDOR_DIRECTORIES: [&str; 3] = [
+    "/usr/local/lib/systemd/user",
+    "/usr/lib/systemd/user",
+    "/usr/share/systemd/user",
+];
+const SYSTEM_CONFIG_DIRECTORIES: [&str; 5] = [
+    "/etc/systemd/system",
+    "/run/systemd/system",
+    "/usr/local/lib/systemd/system",
+    "/usr/lib/systemd/system",
+    "/lib/systemd/system",
+];
+const USER_CONFIG_DIRECTORIES: [&str; 4] = [
+    "/etc/systemd/user",
+    "/run/systemd/user",
+    "/usr/lib/systemd/user",
+    "/usr/share/systemd/user",
+];
+
+/// Interface carrying the cgroup of a unit, only present for units backed by processes:
+/// services, sockets, mounts, swaps, slices and scopes.
+fn cgroup_interface(name: &str) -> Option<&'static str> {
+    match name.rsplit_once('.').map(|(_, suffix)| suffix) {
+        Some("service") => Some("org.freedesktop.systemd1.Service"),
+        Some("socket") => Some("org.freedesktop.systemd1.Socket"),
+        Some("mount") => Some("org.freedesktop.systemd1.Mount"),
+        Some("swap") => Some("org.freedesktop.systemd1.Swap"),
+        Some("slice") => Some("org.freedesktop.systemd1.Slice"),
+        Some("scope") => Some("org.freedesktop.systemd1.Scope"),
+        _ => None,
+    }
+}
+
 type SystemdUnit = (
     String,
     String,
@@ -28,26 +87,65 @@ type SystemdUnit = (
     OwnedObjectPath,
 );
 
+#[derive(Clone, PartialEq, Debug)]
 pub enum ConnectionType {
     Session,
-    System
+    System,
+    /// System bus of another machine, reached through `ssh` and `systemd-stdio-bridge` like `systemctl -H`.
+    Remote(String),
+    /// System bus of a local container registered with machined, like `systemctl -M`.
+    Machine(String),
+}
+
+/// Never prompt on the terminal the TUI is drawn on; keys and agents are expected to be set up.
+const SSH_OPTIONS: [&str; 4] = ["-xT", "-q", "-o", "BatchMode=yes"];
+
+fn connect(connection_type: &ConnectionType) -> Result<Connection, Error> {
+    match connection_type {
+        ConnectionType::Session => Connection::session(),
+        ConnectionType::System => Connection::system(),
+        ConnectionType::Remote(host) => {
+            let args = SSH_OPTIONS
+                .iter()
+                .chain(&["-e", "none", "--", host.as_str(), "systemd-stdio-bridge"])
+                .map(OsString::from)
+                .collect();
+            let transport = Transport::Unixexec(Unixexec::new(PathBuf::from("ssh"), None, args));
+            zbus::blocking::connection::Builder::address(Address::from(transport))?.build()
+        }
+        ConnectionType::Machine(machine) => {
+            let args = vec![OsString::from(format!("--machine={machine}"))];
+            let transport = Transport::Unixexec(Unixexec::new(PathBuf::from("systemd-stdio-bridge"), None, args));
+            zbus::blocking::connection::Builder::address(Address::from(transport))?.build()
+        }
+    }
+}
+
+/// Quotes an argument for the remote shell ssh runs commands with.
+fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
 }
 
+type JobListener = Box<dyn Fn() + Send>;
+
 pub struct SystemdServiceAdapter {
     connection: Connection,
-    connection_type: ConnectionType
+    connection_type: ConnectionType,
+    /// Called on job signals while the jobs view is open.
+    job_listener: Arc<Mutex<Option<JobListener>>>,
+    /// Whether the thread reading job signals runs; there is one per connection, shared by every visit.
+    job_thread: Mutex<bool>,
 }
 
 impl SystemdServiceAdapter {
     pub fn new(connection_type: ConnectionType) -> Result<Self, Error> {
-        let connection = match connection_type {
-            ConnectionType::Session => Connection::session()?,
-            ConnectionType::System => Connection::system()?
-        };
+        let connection = connect(&connection_type)?;
 
         Ok(Self {
             connection, 
-            connection_type
+            connection_type,
+            job_listener: Arc::new(Mutex::new(None)),
+            job_thread: Mutex::new(false),
         })
     }
 
@@ -61,18 +159,171 @@ impl SystemdServiceAdapter {
         Ok(proxy)
     }
 
-}
+    /// Runs `program` on the managed machine: directly, through ssh for remote hosts, or with
+    /// `--machine` for containers, which journalctl and systemctl both understand.
+    fn host_command(&self, program: &str, args: &[String]) -> Command {
+        match &self.connection_type {
+            ConnectionType::Remote(host) => {
+                let mut command = Command::new("ssh");
+                command
+                    .args(SSH_OPTIONS)
+                    .arg("--")
+                    .arg(host)
+                    .arg(program)
+                    .args(args.iter().map(|arg| shell_quote(arg)));
+                command
+            }
+            ConnectionType::Machine(machine) => {
+                let mut command = Command::new(program);
+                command.arg(format!("--machine={machine}")).args(args);
+                command
+            }
+            ConnectionType::System | ConnectionType::Session => {
+                let mut command = Command::new(program);
+                command.args(args);
+                command
+            }
+        }
+    }
 
-impl ServiceRepository for SystemdServiceAdapter {
-    fn change_connection(&mut self, connection_type: ConnectionType) -> Result<(), Error> {
-        self.connection = match connection_type {
-            ConnectionType::Session => Connection::session()?,
-            ConnectionType::System => Connection::system()?
+    /// Fails for remote hosts and containers, for features that read files or processes of this machine.
+    fn ensure_local(&self, feature: &str) -> Result<(), Box<dyn std::error::Error>> {
+        match &self.connection_type {
+            ConnectionType::Remote(host) => Err(format!("{feature} is not available on remote hosts ({host})").into()),
+            ConnectionType::Machine(machine) => Err(format!("{feature} is not available inside containers ({machine})").into()),
+            ConnectionType::System | ConnectionType::Session => Ok(()),
+        }
+    }
+
+    /// Unit file path with its directory resolved, so /lib/systemd/system/x.service and
+    /// /usr/lib/systemd/system/x.service compare equal on merged-/usr systems. The file itself is
+    /// not followed, keeping a unit symlinked to its vendor file distinct from it.
+    fn resolved_path(path: &str) -> String {
+        let path = Path::new(path);
+        match (path.parent().and_then(|parent| fs::canonicalize(parent).ok()), path.file_name()) {
+            (Some(directory), Some(name)) => directory.join(name).display().to_string(),
+            _ => path.display().to_string(),
+        }
+    }
+
+    fn config_directories(&self) -> Vec<PathBuf> {
+        let mut directories: Vec<PathBuf> = match self.connection_type {
+            ConnectionType::System | ConnectionType::Remote(_) | ConnectionType::Machine(_) => SYSTEM_CONFIG_DIRECTORIES.iter().map(PathBuf::from).collect(),
+            ConnectionType::Session => self
+                .unit_file_directory()
+                .ok()
+                .into_iter()
+                .chain(USER_CONFIG_DIRECTORIES.iter().map(PathBuf::from))
+                .collect(),
         };
-        self.connection_type = connection_type;
-        Ok(())
+        let mut seen = Vec::new();
+        directories.retain(|directory| {
+            let canonical = fs::canonicalize(directory).unwrap_or_else(|_| directory.clone());
+            if seen.contains(&canonical) {
+                false
+            } else {
+                seen.push(canonical);
+                true
+            }
+        });
+        directories
+    }
+
+    fn classify_unit_file(&self, path: &str, state: &str, directories: &[PathBuf]) -> Vec<UnitOverride> {
+        let name = path.rsplit('/').next().unwrap_or(path).to_string();
+        let vendor = self.vendor_unit_path(&name);
+        let link = fs::read_link(path).ok();
+        let mut overrides = Vec::new();
+
+        if state.starts_with("masked") || link.as_deref() == Some(Path::new("/dev/null")) {
+            overrides.push(UnitOverride {
+                name: name.clone(),
+                category: DeltaCategory::Masked,
+                path: path.to_string(),
+                target: vendor,
+            });
+            return overrides;
+        }
+
+        // vendor_unit_path is resolved, and ListUnitFiles may report the /lib spelling. It never
+        // returns a /run path, so a runtime copy of a packaged unit is compared to it as an override.
+        if let Some(vendor) = vendor.filter(|vendor| *vendor != Self::resolved_path(path)) {
+            let (category, target) = match link {
+                Some(link) => (DeltaCategory::Redirected, link.display().to_string()),
+                None if fs::read(path).ok() == fs::read(&vendor).ok() => (DeltaCategory::Equivalent, vendor),
+                None => (DeltaCategory::Overridden, vendor),
+            };
+            overrides.push(UnitOverride {
+                name: name.clone(),
+                category,
+                path: path.to_string(),
+                target: Some(target),
+            });
+        }
+
+        for directory in directories {
+            let Ok(entries) = fs::read_dir(directory.join(format!("{name}.d"))) else {
+                continue;
+            };
+            let mut drop_ins: Vec<PathBuf> = entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|drop_in| drop_in.extension().is_some_and(|extension| extension == "conf"))
+                .collect();
+            drop_ins.sort();
+            overrides.extend(drop_ins.into_iter().map(|drop_in| UnitOverride {
+                name: name.clone(),
+                category: DeltaCategory::Extended,
+                path: drop_in.display().to_string(),
+                target: Some(path.to_string()),
+            }));
+        }
+
+        overrides
+    }
+
+    /// Queues a job through a Manager method such as `StartUnit`, returning the unit once the job is accepted.
+    fn unit_job(&self, method: &str, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let reply: Option<OwnedObjectPath> = proxy.call_with_flags(
+            method,
+            MethodFlags::AllowInteractiveAuth.into(),
+            &(name, mode.as_str())
+        )?;
+        reply.ok_or(format!("No reply from {method}"))?;
+        thread::sleep(Duration::from_millis(SLEEP_DURATION));
+        self.get_unit(name)
+    }
+
+    fn unit_properties(&self, name: &str, interface: &str) -> Result<HashMap<String, OwnedValue>, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let unit_path: OwnedObjectPath = proxy.call("LoadUnit", &name)?;
+        let unit_proxy = Proxy::new(
+            &self.connection,
+            "org.freedesktop.systemd1",
+            unit_path.as_ref(),
+            "org.freedesktop.DBus.Properties",
+        )?;
+        let properties: HashMap<String, OwnedValue> = unit_proxy.call("GetAll", &(interface))?;
+        Ok(properties)
     }
 
+    fn unit_property(&self, name: &str, interface: &str, property: &str) -> Result<OwnedValue, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let unit_path: OwnedObjectPath = proxy.call("LoadUnit", &name)?;
+        let unit_proxy = Proxy::new(
+            &self.connection,
+            "org.freedesktop.systemd1",
+            unit_path.as_ref(),
+            "org.freedesktop.DBus.Properties",
+        )?;
+        let variant: OwnedValue = unit_proxy.call("Get", &(interface, property))?;
+        Ok(variant)
+    }
+
+}
+
+impl ServiceRepository for SystemdServiceAdapter {
     fn unit_files_state(
         &self,
         services: Vec<Service>
@@ -167,18 +418,30 @@ impl ServiceRepository for SystemdServiceAdapter {
         Ok(services)
     }
 
-    fn get_service_log(&self, name: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let mut cmd = std::process::Command::new("journalctl");
+    fn list_unit_overrides(&self) -> Result<Vec<UnitOverride>, Box<dyn std::error::Error>> {
+        self.ensure_local("Listing overridden units")?;
+        let proxy = self.manager_proxy()?;
+
+        let units: Vec<(String, String)> = proxy.call("ListUnitFiles", &())?;
+        let directories = self.config_directories();
 
-        cmd.arg("-e")
-            .arg(format!("--unit={name}"))
-            .arg("--no-pager");
+        let mut overrides = units
+            .into_par_iter()
+            .flat_map(|(path, state)| self.classify_unit_file(&path, &state, &directories))
+            .collect::<Vec<_>>();
+        overrides.sort_by(|a, b| a.name.cmp(&b.name).then(a.path.cmp(&b.path)));
+
+        Ok(overrides)
+    }
+
+    fn get_service_log(&self, name: &str, lines: usize) -> Result<String, Box<dyn std::error::Error>> {
+        let mut args = vec![format!("--lines={lines}"), format!("--unit={name}"), "--no-pager".to_string()];
 
         if matches!(self.connection_type, ConnectionType::Session){
-            cmd.arg("--user");
+            args.push("--user".to_string());
         }
 
-        let output = cmd
+        let output = self.host_command("journalctl", &args)
             .output()?;
 
         let log = if output.status.success() {
@@ -190,21 +453,144 @@ impl ServiceRepository for SystemdServiceAdapter {
         Ok(log)
     }
     
-    fn systemctl_cat(&self, name: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let mut cmd = Command::new("systemctl");
+    fn get_service_log_tail(&self, name: &str, lines: usize) -> Result<String, Box<dyn std::error::Error>> {
+        let mut args = vec![
+            format!("--lines={lines}"),
+            format!("--unit={name}"),
+            "--no-pager".to_string(),
+            "--quiet".to_string(),
+        ];
+
+        if matches!(self.connection_type, ConnectionType::Session){
+            args.push("--user".to_string());
+        }
+
+        let output = self.host_command("journalctl", &args).output()?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Ok(String::from_utf8_lossy(&output.stderr).to_string())
+        }
+    }
 
-        cmd
-            .arg("cat")
-            .arg("--no-pager");
+    fn unit_status(&self, name: &str) -> Result<UnitStatus, Box<dyn std::error::Error>> {
+        fn string(properties: &HashMap<String, OwnedValue>, key: &str) -> String {
+            properties
+                .get(key)
+                .and_then(|value| String::try_from(value.try_clone().ok()?).ok())
+                .unwrap_or_default()
+        }
+        // systemd reports unset counters as UINT64_MAX.
+        fn counter(properties: &HashMap<String, OwnedValue>, key: &str) -> Option<u64> {
+            properties
+                .get(key)
+                .and_then(|value| u64::try_from(value).ok())
+                .filter(|value| *value != u64::MAX)
+        }
+
+        let unit = self.unit_properties(name, "org.freedesktop.systemd1.Unit")?;
+        let mut status = UnitStatus {
+            name: name.to_string(),
+            description: string(&unit, "Description"),
+            load_state: string(&unit, "LoadState"),
+            fragment_path: string(&unit, "FragmentPath"),
+            unit_file_state: string(&unit, "UnitFileState"),
+            unit_file_preset: string(&unit, "UnitFilePreset"),
+            active_state: string(&unit, "ActiveState"),
+            sub_state: string(&unit, "SubState"),
+            state_change_timestamp: unit
+                .get("StateChangeTimestamp")
+                .and_then(|value| u64::try_from(value).ok())
+                .unwrap_or_default(),
+            ..UnitStatus::default()
+        };
+
+        let Some(interface) = cgroup_interface(name) else {
+            return Ok(status);
+        };
+        let Ok(runtime) = self.unit_properties(name, interface) else {
+            return Ok(status);
+        };
+
+        status.main_pid = runtime
+            .get("MainPID")
+            .and_then(|value| u32::try_from(value).ok())
+            .filter(|pid| *pid != 0);
+        status.tasks_current = counter(&runtime, "TasksCurrent");
+        status.tasks_max = counter(&runtime, "TasksMax");
+        status.memory_current = counter(&runtime, "MemoryCurrent");
+        status.cpu_usage_nsec = counter(&runtime, "CPUUsageNSec");
+        status.control_group = Some(string(&runtime, "ControlGroup")).filter(|cgroup| !cgroup.is_empty());
+
+        Ok(status)
+    }
+
+    fn security_settings(&self, name: &str) -> Result<SecuritySettings, Box<dyn std::error::Error>> {
+        let properties = self.unit_properties(name, "org.freedesktop.systemd1.Service")?;
+        let flag = |key: &str| properties.get(key).and_then(|value| bool::try_from(value).ok()).unwrap_or_default();
+        // Masks default to everything allowed, as systemd does, so an unreadable value never scores as hardened.
+        let number = |key: &str| properties.get(key).and_then(|value| u64::try_from(value).ok()).unwrap_or(u64::MAX);
+        let string = |key: &str| {
+            properties
+                .get(key)
+                .and_then(|value| String::try_from(value.try_clone().ok()?).ok())
+                .unwrap_or_default()
+        };
+        let list = |key: &str| {
+            properties
+                .get(key)
+                .and_then(|value| Vec::<String>::try_from(value.try_clone().ok()?).ok())
+                .unwrap_or_default()
+        };
+        let filter = |key: &str| {
+            properties
+                .get(key)
+                .and_then(|value| <(bool, Vec<String>)>::try_from(value.try_clone().ok()?).ok())
+                .unwrap_or_default()
+        };
+
+        Ok(SecuritySettings {
+            user: string("User"),
+            dynamic_user: flag("DynamicUser"),
+            no_new_privileges: flag("NoNewPrivileges"),
+            private_tmp: flag("PrivateTmp"),
+            private_devices: flag("PrivateDevices"),
+            private_network: flag("PrivateNetwork"),
+            private_users: flag("PrivateUsers"),
+            private_mounts: flag("PrivateMounts"),
+            protect_system: string("ProtectSystem"),
+            protect_home: string("ProtectHome"),
+            protect_proc: string("ProtectProc"),
+            protect_kernel_tunables: flag("ProtectKernelTunables"),
+            protect_kernel_modules: flag("ProtectKernelModules"),
+            protect_kernel_logs: flag("ProtectKernelLogs"),
+            protect_control_groups: flag("ProtectControlGroups"),
+            protect_clock: flag("ProtectClock"),
+            protect_hostname: flag("ProtectHostname"),
+            restrict_suid_sgid: flag("RestrictSUIDSGID"),
+            restrict_realtime: flag("RestrictRealtime"),
+            lock_personality: flag("LockPersonality"),
+            memory_deny_write_execute: flag("MemoryDenyWriteExecute"),
+            remove_ipc: flag("RemoveIPC"),
+            device_policy: string("DevicePolicy"),
+            restrict_namespaces: number("RestrictNamespaces"),
+            capability_bounding_set: number("CapabilityBoundingSet"),
+            system_call_filter: filter("SystemCallFilter"),
+            system_call_architectures: list("SystemCallArchitectures"),
+            restrict_address_families: filter("RestrictAddressFamilies"),
+        })
+    }
+
+    fn systemctl_cat(&self, name: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let mut args = vec!["cat".to_string(), "--no-pager".to_string()];
 
         if matches!(self.connection_type, ConnectionType::Session){
-            cmd
-                .arg("--user");
+            args.push("--user".to_string());
         }
+        args.extend(["--".to_string(), name.to_string()]);
 
-        let output = cmd
-            .arg("--")
-            .arg(name)
+        let output = self.host_command("systemctl", &args)
             .output()?;
 
         if output.status.success() {
@@ -233,55 +619,38 @@ impl ServiceRepository for SystemdServiceAdapter {
         }
     }
 
-    fn start_service(&self, name: &str) -> Result<Service, Box<dyn std::error::Error>> {
-        let proxy = self.manager_proxy()?;
-        let reply: Option<OwnedObjectPath> = proxy.call_with_flags(
-            "StartUnit", 
-            MethodFlags::AllowInteractiveAuth.into(),  
-            &(name, "replace")
-        )?;
-        reply.ok_or("No reply from StartUnit")?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        let mut service = self.get_unit(name)?;
-        while service.state().active().ends_with("ing") {
-            service = self.get_unit(name)?;
-            thread::sleep(Duration::from_millis(100));
-        }
-        Ok(service)
+    fn start_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("StartUnit", name, mode)
     }
 
-    fn stop_service(&self, name: &str) -> Result<Service, Box<dyn std::error::Error>> {
-        let proxy = self.manager_proxy()?;
-        let reply: Option<OwnedObjectPath> = proxy.call_with_flags(
-            "StopUnit", 
-            MethodFlags::AllowInteractiveAuth.into(), 
-            &(name.to_string(), "replace")
-        )?;
-        reply.ok_or("No reply from StopUnit")?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        let mut service = self.get_unit(name)?;
-        while service.state().active().ends_with("ing") {
-            service = self.get_unit(name)?;
-            thread::sleep(Duration::from_millis(100));
-        }
-        Ok(service)
+    fn stop_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("StopUnit", name, mode)
     }
 
-    fn restart_service(&self, name: &str) -> Result<Service, Box<dyn std::error::Error>> {
-        let proxy = self.manager_proxy()?;
-        let reply: Option<OwnedObjectPath> = proxy.call_with_flags(
-            "RestartUnit", 
-            MethodFlags::AllowInteractiveAuth.into(), 
-            &(name, "replace")
-        )?;
-        reply.ok_or("No reply from Start")?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        let mut service = self.get_unit(name)?;
-        while service.state().active().ends_with("ing") {
-            service = self.get_unit(name)?;
-            thread::sleep(Duration::from_millis(100));
-        }
-        Ok(service)
+    fn restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("RestartUnit", name, mode)
+    }
+
+    fn reload_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("ReloadUnit", name, mode)
+    }
+
+    fn reload_or_restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("ReloadOrRestartUnit", name, mode)
+    }
+
+    fn try_restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("TryRestartUnit", name, mode)
+    }
+
+    fn reload_or_try_restart_service(&self, name: &str, mode: JobMode) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("ReloadOrTryRestartUnit", name, mode)
+    }
+
+    fn can_reload(&self, name: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let variant = self.unit_property(name, "org.freedesktop.systemd1.Unit", "CanReload")?;
+        let can_reload: bool = variant.try_into()?;
+        Ok(can_reload)
     }
 
     fn enable_service(&self, name: &str) -> Result<Service, Box<dyn std::error::Error>> {
@@ -337,6 +706,347 @@ impl ServiceRepository for SystemdServiceAdapter {
         self.get_unit(name)
     }
 
+    fn reset_failed_unit(&self, name: &str) -> Result<Service, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        proxy.call_with_flags::<_, _, ()>(
+            "ResetFailedUnit",
+            MethodFlags::AllowInteractiveAuth.into(),
+            &(name),
+        )?;
+        self.get_unit(name)
+    }
+
+    fn reset_failed(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        proxy.call_with_flags::<_, _, ()>("ResetFailed", MethodFlags::AllowInteractiveAuth.into(), &())?;
+        Ok(())
+    }
+
+    fn kill_unit(&self, name: &str, whom: &str, signal: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        proxy.call_with_flags::<_, _, ()>(
+            "KillUnit",
+            MethodFlags::AllowInteractiveAuth.into(),
+            &(name, whom, signal),
+        )?;
+        thread::sleep(Duration::from_millis(SLEEP_DURATION));
+        Ok(())
+    }
+
+    fn unit_processes(&self, name: &str) -> Result<Vec<UnitProcess>, Box<dyn std::error::Error>> {
+        self.ensure_local("The process view")?;
+        let proxy = self.manager_proxy()?;
+        let processes: Vec<(String, u32, String)> = proxy.call("GetUnitProcesses", &(name))?;
+        let users = procfs::read_users();
+
+        Ok(processes
+            .into_iter()
+            .map(|(cgroup, pid, command)| procfs::read_process(pid, cgroup, command, &users))
+            .collect())
+    }
+
+    fn kill_process(&self, pid: u32, signal: i32) -> Result<(), Box<dyn std::error::Error>> {
+        self.ensure_local("Signalling a single process")?;
+        let pid = libc::pid_t::try_from(pid)?;
+        // SAFETY: kill has no memory safety preconditions
+        if unsafe { libc::kill(pid, signal) } != 0 {
+            return Err(Box::new(io::Error::last_os_error()));
+        }
+        Ok(())
+    }
+
+    fn default_target(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let target: String = proxy.call("GetDefaultTarget", &())?;
+        Ok(target)
+    }
+
+    fn set_default_target(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let _changes: Vec<(String, String, String)> = proxy.call_with_flags(
+            "SetDefaultTarget",
+            MethodFlags::AllowInteractiveAuth.into(),
+            &(name, true),
+        )?.ok_or("No reply from SetDefaultTarget")?;
+        thread::sleep(Duration::from_millis(SLEEP_DURATION));
+        Ok(())
+    }
+
+    fn list_targets(&self) -> Result<Vec<TargetUnit>, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let units: Vec<SystemdUnit> = proxy.call("ListUnitsByPatterns", &(Vec::<&str>::new(), vec!["*.target"]))?;
+        let files: Vec<(String, String)> = proxy.call("ListUnitFilesByPatterns", &(Vec::<&str>::new(), vec!["*.target"]))?;
+
+        let mut targets: HashMap<String, TargetUnit> = units
+            .into_iter()
+            .map(|unit| {
+                let target = TargetUnit {
+                    name: unit.0.clone(),
+                    description: unit.1,
+                    active_state: unit.3,
+                    allow_isolate: false,
+                };
+                (unit.0, target)
+            })
+            .collect();
+        for (path, state) in files {
+            let name = path.rsplit('/').next().unwrap_or(&path).to_string();
+            // Templates and masked targets cannot be started.
+            if name.contains("@.") || state.starts_with("masked") {
+                continue;
+            }
+            targets.entry(name.clone()).or_insert_with(|| TargetUnit {
+                name,
+                active_state: "inactive".to_string(),
+                ..TargetUnit::default()
+            });
+        }
+
+        let mut targets: Vec<TargetUnit> = targets
+            .into_par_iter()
+            .map(|(_, mut target)| {
+                target.allow_isolate = self
+                    .unit_property(&target.name, "org.freedesktop.systemd1.Unit", "AllowIsolate")
+                    .ok()
+                    .and_then(|value| bool::try_from(value).ok())
+                    .unwrap_or_default();
+                if target.description.is_empty() {
+                    target.description = self
+                        .unit_property(&target.name, "org.freedesktop.systemd1.Unit", "Description")
+                        .ok()
+                        .and_then(|value| String::try_from(value).ok())
+                        .unwrap_or_default();
+                }
+                target
+            })
+            .collect();
+        targets.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(targets)
+    }
+
+    fn isolate_target(&self, name: &str) -> Result<Service, Box<dyn std::error::Error>> {
+        self.unit_job("StartUnit", name, JobMode::Isolate)
+    }
+
+    fn boot_timestamps(&self) -> Result<BootTimestamps, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let timestamp = |property: &str| proxy.get_property::<u64>(property);
+        Ok(BootTimestamps {
+            firmware: timestamp("FirmwareTimestampMonotonic")?,
+            loader: timestamp("LoaderTimestampMonotonic")?,
+            initrd: timestamp("InitRDTimestampMonotonic")?,
+            userspace: timestamp("UserspaceTimestampMonotonic")?,
+            finish: timestamp("FinishTimestampMonotonic")?,
+        })
+    }
+
+    fn unit_activations(&self) -> Result<Vec<UnitActivation>, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &())?;
+
+        let activations = units
+            .into_par_iter()
+            .filter_map(|unit| {
+                let unit_proxy = Proxy::new(
+                    &self.connection,
+                    "org.freedesktop.systemd1",
+                    unit.6,
+                    "org.freedesktop.DBus.Properties",
+                )
+                .ok()?;
+                let properties: HashMap<String, OwnedValue> =
+                    unit_proxy.call("GetAll", &("org.freedesktop.systemd1.Unit")).ok()?;
+                let timestamp = |key: &str| {
+                    properties.get(key).and_then(|value| u64::try_from(value).ok()).unwrap_or_default()
+                };
+                let after = properties
+                    .get("After")
+                    .and_then(|value| Vec::<String>::try_from(value.try_clone().ok()?).ok())
+                    .unwrap_or_default();
+
+                Some(UnitActivation {
+                    activating: timestamp("InactiveExitTimestampMonotonic"),
+                    activated: timestamp("ActiveEnterTimestampMonotonic"),
+                    name: unit.0,
+                    after,
+                })
+            })
+            .collect();
+
+        Ok(activations)
+    }
+
+    fn start_transient_unit(&self, unit: &TransientUnit) -> Result<String, Box<dyn std::error::Error>> {
+        unit.validate()?;
+        let name = unit.unit_name();
+        let argv = unit.argv();
+        let environment = unit.environment();
+        let working_directory = unit.working_directory.trim();
+
+        let mut properties: Vec<(&str, Value)> = vec![("Description", Value::from(argv.join(" ")))];
+        if let Some(memory_max) = unit.memory_max()? {
+            properties.push(("MemoryMax", Value::from(memory_max)));
+        }
+        if let Some(cpu_quota) = unit.cpu_quota()? {
+            properties.push(("CPUQuotaPerSecUSec", Value::from(cpu_quota)));
+        }
+
+        let proxy = self.manager_proxy()?;
+        let start = |name: &str, properties: Vec<(&str, Value)>, auxiliary: Vec<(String, Vec<(&str, Value)>)>| {
+            proxy.call_with_flags::<_, _, OwnedObjectPath>(
+                "StartTransientUnit",
+                MethodFlags::AllowInteractiveAuth.into(),
+                &(name, "fail", properties, auxiliary),
+            )
+        };
+
+        match unit.kind {
+            TransientKind::Service => {
+                properties.push(("ExecStart", Value::from(vec![(argv[0].clone(), argv, false)])));
+                if !environment.is_empty() {
+                    properties.push(("Environment", Value::from(environment)));
+                }
+                if !working_directory.is_empty() {
+                    properties.push(("WorkingDirectory", Value::from(working_directory)));
+                }
+
+                match unit.timer_name(&name) {
+                    Some(timer) => {
+                        let timer_properties = vec![
+                            ("Description", Value::from(format!("Timer for {name}"))),
+                            ("TimersCalendar", Value::from(vec![("OnCalendar", unit.on_calendar.trim())])),
+                            ("RemainAfterElapse", Value::from(false)),
+                        ];
+                        start(&timer, timer_properties, vec![(name.clone(), properties)])?;
+                    }
+                    None => {
+                        start(&name, properties, Vec::new())?;
+                    }
+                }
+            }
+            TransientKind::Scope => {
+                self.ensure_local("Running a scope")?;
+                // systemd-cat execs the command with its output connected to the journal, so the
+                // scope has a log and nothing is written over the terminal.
+                let mut command = Command::new("systemd-cat");
+                command
+                    .arg(format!("--identifier={}", name.trim_end_matches(".scope")))
+                    .arg("--")
+                    .args(&argv)
+                    .envs(environment.iter().filter_map(|assignment| assignment.split_once('=')))
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null());
+                // "~" is only understood by systemd, which gets it for services; the scope's command
+                // is started from here.
+                if working_directory == "~" {
+                    let home = std::env::var_os("HOME").ok_or("HOME is not set, can not resolve '~'")?;
+                    command.current_dir(home);
+                } else if !working_directory.is_empty() {
+                    command.current_dir(working_directory);
+                }
+                let mut child = command.spawn()?;
+
+                properties.push(("PIDs", Value::from(vec![child.id()])));
+                if let Err(e) = start(&name, properties, Vec::new()) {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(e.into());
+                }
+                thread::spawn(move || child.wait());
+            }
+        }
+        Ok(name)
+    }
+
+    fn resource_controls(&self, name: &str) -> Result<Vec<(ResourceControl, u64)>, Box<dyn std::error::Error>> {
+        let interface = cgroup_interface(name).ok_or(format!("'{name}' has no resource controls"))?;
+        let properties = self.unit_properties(name, interface)?;
+        Ok(ResourceControl::ALL
+            .into_iter()
+            .filter_map(|control| {
+                let value = properties.get(control.property()).and_then(|value| u64::try_from(value).ok())?;
+                Some((control, value))
+            })
+            .collect())
+    }
+
+    fn set_resource_controls(&self, name: &str, runtime: bool, values: &[(ResourceControl, u64)]) -> Result<(), Box<dyn std::error::Error>> {
+        let properties: Vec<(&str, Value)> =
+            values.iter().map(|(control, value)| (control.property(), Value::from(*value))).collect();
+        let proxy = self.manager_proxy()?;
+        proxy.call_with_flags::<_, _, ()>(
+            "SetUnitProperties",
+            MethodFlags::AllowInteractiveAuth.into(),
+            &(name, runtime, properties),
+        )?;
+        Ok(())
+    }
+
+    fn list_jobs(&self) -> Result<Vec<UnitJob>, Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        let jobs: Vec<(u32, String, String, String, OwnedObjectPath, OwnedObjectPath)> = proxy.call("ListJobs", &())?;
+
+        let mut jobs: Vec<UnitJob> = jobs
+            .into_iter()
+            .map(|(id, unit, job_type, state, ..)| UnitJob { id, unit, job_type, state })
+            .collect();
+        jobs.sort_by_key(|job| job.id);
+        Ok(jobs)
+    }
+
+    fn cancel_job(&self, id: u32) -> Result<(), Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        proxy.call_with_flags::<_, _, ()>("CancelJob", MethodFlags::AllowInteractiveAuth.into(), &(id))?;
+        Ok(())
+    }
+
+    fn clear_jobs(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let proxy = self.manager_proxy()?;
+        proxy.call_with_flags::<_, _, ()>("ClearJobs", MethodFlags::AllowInteractiveAuth.into(), &())?;
+        Ok(())
+    }
+
+    fn watch_jobs(&self, on_change: Box<dyn Fn() + Send>) -> Result<(), Box<dyn std::error::Error>> {
+        let mut job_thread = self.job_thread.lock().unwrap();
+        if !*job_thread {
+            let rule = MatchRule::builder()
+                .msg_type(Type::Signal)
+                .sender("org.freedesktop.systemd1")?
+                .interface("org.freedesktop.systemd1.Manager")?
+                .build();
+            let iter = MessageIterator::for_match_rule(rule, &self.connection, Some(64))?;
+            let listener = Arc::clone(&self.job_listener);
+            thread::spawn(move || {
+                for message in iter.flatten() {
+                    let member = message.header().member().map(|member| member.to_string());
+                    if matches!(member.as_deref(), Some("JobNew" | "JobRemoved"))
+                        && let Some(on_change) = listener.lock().unwrap().as_ref()
+                    {
+                        on_change();
+                    }
+                }
+            });
+            *job_thread = true;
+        }
+
+        // The manager only emits job signals while at least one client is subscribed.
+        let mut listener = self.job_listener.lock().unwrap();
+        if listener.is_none() {
+            self.manager_proxy()?.call::<_, _, ()>("Subscribe", &())?;
+        }
+        *listener = Some(on_change);
+        Ok(())
+    }
+
+    fn unwatch_jobs(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.job_listener.lock().unwrap().take().is_some() {
+            self.manager_proxy()?.call::<_, _, ()>("Unsubscribe", &())?;
+        }
+        Ok(())
+    }
+
     fn reload_daemon(&self) -> Result<(), Box<dyn std::error::Error>> {
         let proxy = self.manager_proxy()?;
         proxy.call_with_flags::<&str, (), ()>("Reload", MethodFlags::AllowInteractiveAuth.into(), &())?;
@@ -345,21 +1055,154 @@ impl ServiceRepository for SystemdServiceAdapter {
     }
 
     fn get_active_enter_timestamp(&self, name: &str) -> Result<u64, Box<dyn std::error::Error>> {
-        let proxy = self.manager_proxy()?;
-        let unit_path: OwnedObjectPath = proxy.call("LoadUnit", &name)?;
-        let unit_proxy = Proxy::new(
-            &self.connection,
-            "org.freedesktop.systemd1",
-            unit_path.as_ref(),
-            "org.freedesktop.DBus.Properties",
-        )?;
-        let variant: OwnedValue = unit_proxy.call(
-            "Get",
-            &("org.freedesktop.systemd1.Unit", "ActiveEnterTimestamp"),
-        )?;
+        let variant = self.unit_property(name, "org.freedesktop.systemd1.Unit", "ActiveEnterTimestamp")?;
         let timestamp: u64 = variant.try_into()?;
         Ok(timestamp)
     }
 
+    fn unit_file_directory(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        match self.connection_type {
+            ConnectionType::System | ConnectionType::Remote(_) | ConnectionType::Machine(_) => Ok(PathBuf::from("/etc/systemd/system")),
+            // A relative path would put the units under the current directory.
+            ConnectionType::Session => std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .filter(|directory| directory.is_absolute())
+                .or_else(|| {
+                    std::env::var_os("HOME")
+                        .map(PathBuf::from)
+                        .filter(|home| home.is_absolute())
+                        .map(|home| home.join(".config"))
+                })
+                .map(|directory| directory.join("systemd/user"))
+                .ok_or_else(|| "Neither XDG_CONFIG_HOME nor HOME is set, can not locate the user unit directory".into()),
+        }
+    }
+
+    fn create_unit_file(&self, file_name: &str, content: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        self.ensure_local("Creating unit files")?;
+        let directory = self.unit_file_directory()?;
+        fs::create_dir_all(&directory)?;
+
+        let path = directory.join(file_name);
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::AlreadyExists => format!("{} already exists", path.display()),
+                _ => format!("Failed to create {}: {e}", path.display()),
+            })?;
+        if let Err(e) = file.write_all(content.as_bytes()) {
+            let _ = fs::remove_file(&path);
+            return Err(e.into());
+        }
+        Ok(path)
+    }
+
+    fn write_unit_file(&self, file_name: &str, content: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        self.ensure_local("Editing unit files")?;
+        let directory = self.unit_file_directory()?;
+        fs::create_dir_all(&directory)?;
+
+        let path = directory.join(file_name);
+        let existing = match fs::symlink_metadata(&path) {
+            Ok(metadata) => Some(metadata),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        // Writing through a symlink would fill /dev/null for a masked unit, or the linked file elsewhere.
+        if existing.as_ref().is_some_and(fs::Metadata::is_symlink) {
+            let target = fs::read_link(&path)?;
+            return Err(if target == Path::new("/dev/null") {
+                format!("{} is masked, unmask it before editing", path.display())
+            } else {
+                format!("{} is a link to {}, edit that file instead", path.display(), target.display())
+            }
+            .into());
+        }
+
+        // Written beside the unit and renamed over it, so a failed write leaves the old file intact.
+        let mut file = tempfile::Builder::new().prefix(".systemd-manager-tui-").tempfile_in(&directory)?;
+        file.write_all(content.as_bytes())?;
+        let permissions = existing.map_or_else(|| fs::Permissions::from_mode(0o644), |metadata| metadata.permissions());
+        file.as_file().set_permissions(permissions)?;
+        file.persist(&path).map_err(|e| e.error)?;
+        Ok(path)
+    }
+
+    fn unit_fragment_path(&self, name: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let variant = self.unit_property(name, "org.freedesktop.systemd1.Unit", "FragmentPath")?;
+        let path: String = variant.try_into()?;
+        match self.connection_type {
+            // Resolved like vendor_unit_path, the files of remote hosts are not readable here.
+            ConnectionType::System | ConnectionType::Session if !path.is_empty() => Ok(Self::resolved_path(&path)),
+            _ => Ok(path),
+        }
+    }
+
+    fn unit_drop_in_paths(&self, name: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let variant = self.unit_property(name, "org.freedesktop.systemd1.Unit", "DropInPaths")?;
+        let paths: Vec<String> = variant.try_into()?;
+        Ok(paths)
+    }
+
+    fn vendor_unit_path(&self, name: &str) -> Option<String> {
+        let directories = match self.connection_type {
+            ConnectionType::System => SYSTEM_VENDOR_DIRECTORIES,
+            ConnectionType::Session => USER_VENDOR_DIRECTORIES,
+            ConnectionType::Remote(_) | ConnectionType::Machine(_) => return None,
+        };
+
+        directories
+            .iter()
+            .map(|directory| PathBuf::from(directory).join(name))
+            .find(|path| path.exists())
+            .map(|path| Self::resolved_path(&path.display().to_string()))
+    }
+
+    fn read_unit_file(&self, path: &str) -> Result<String, Box<dyn std::error::Error>> {
+        self.ensure_local("Reading unit files")?;
+        Ok(fs::read_to_string(path)?)
+    }
+
+    fn verify_unit_file(&self, file_name: &str, content: &str) -> Result<Vec<Diagnostic>, Box<dyn std::error::Error>> {
+        self.ensure_local("Verifying unit files")?;
+        if file_name.contains('/') || file_name.starts_with('.') {
+            return Err(format!("Invalid unit file name '{file_name}'").into());
+        }
+        // A fresh 0700 directory and an exclusively created file, so nothing planted in the shared
+        // temp directory can redirect the write; this usually runs as root.
+        let directory = tempfile::Builder::new().prefix("systemd-manager-tui-").tempdir()?;
+        let path = directory.path().join(file_name);
+        fs::OpenOptions::new().write(true).create_new(true).open(&path)?.write_all(content.as_bytes())?;
+
+        let mut cmd = Command::new("systemd-analyze");
+        cmd.arg("verify");
+
+        if matches!(self.connection_type, ConnectionType::Session){
+            cmd.arg("--user");
+        }
+
+        let output = cmd.arg(&path).output();
+        let _ = directory.close();
+        let output = output?;
+
+        let prefix = format!("{}:", path.display());
+        let diagnostics = String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .chain(String::from_utf8_lossy(&output.stdout).lines())
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| match line.strip_prefix(&prefix).and_then(|rest| rest.split_once(':')) {
+                Some((number, message)) if number.parse::<usize>().is_ok() => Diagnostic {
+                    line: number.parse::<usize>().ok().map(|n| n.saturating_sub(1)),
+                    message: message.trim().to_string(),
+                },
+                _ => Diagnostic { line: None, message: line.replace(&prefix, "").trim().to_string() },
+            })
+            .collect();
+
+        Ok(diagnostics)
+    }
+
 
 }
@@ -1,10 +1,13 @@
+mod config;
 mod domain;
 mod infrastructure;
 mod terminal;
 mod usecases;
 use infrastructure::systemd_service_adapter::{ConnectionType, SystemdServiceAdapter};
+use config::{Config, FileConfig, StartView};
 use infrastructure::notifier::start_notifier;
 use terminal::app::App;
+use terminal::theme::set_theme;
 use usecases::services_manager::ServicesManager;
 
 use std::cell::RefCell;
@@ -15,10 +18,12 @@ use terminal::app::AppEvent;
 
 use terminal::components::details::ServiceDetails;
 use terminal::components::filter::Filter;
-use terminal::components::list::TableServices;
+use terminal::components::list::{ActiveFilterState, TableServices};
 use terminal::components::log::ServiceLog;
 
 use clap::Parser;
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::execute;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -26,41 +31,123 @@ struct Args {
     /// Filter text applied on startup
     #[arg(short, long)]
     filter: Option<String>,
-}
 
-#[derive(Clone)]
-pub struct Config {
-    pub filter: String,
+    /// Manage the system units of a remote host over ssh, like `systemctl -H`; repeat to list more hosts
+    #[arg(short = 'H', long = "host", value_name = "HOST")]
+    hosts: Vec<String>,
+
+    /// Start on the session units tab, like `systemctl --user`
+    #[arg(long, conflicts_with = "hosts")]
+    user: bool,
+
+    /// Start on the system units tab, overriding `default-tab` of the config file
+    #[arg(long, conflicts_with = "user")]
+    system: bool,
+
+    /// Select a unit on startup and open it; ".service" is assumed without a suffix
+    #[arg(short, long, value_name = "NAME")]
+    unit: Option<String>,
+
+    /// Only list units in this active state on startup
+    #[arg(long, value_enum)]
+    state: Option<ActiveFilterState>,
+
+    /// List all units instead of only services on startup
+    #[arg(short, long)]
+    all: bool,
+
+    /// List only services on startup, overriding `all-units` of the config file
+    #[arg(long, conflicts_with = "all")]
+    services: bool,
+
+    /// View opened for --unit
+    #[arg(long, value_enum, default_value_t = StartView::Status, requires = "unit")]
+    view: StartView,
+
+    /// Color theme: dark, light, high-contrast, monochrome or one defined in the config file
+    #[arg(long, value_name = "NAME")]
+    theme: Option<String>,
 }
 
-impl From<Args> for Config {
-    fn from(args: Args) -> Self {
-        Self {
-            filter: args.filter.unwrap_or_default(),
+impl Args {
+    /// Command line flags take precedence over the config file.
+    fn apply(self, config: &mut Config) {
+        config.filter = self.filter.unwrap_or_default();
+        config.hosts = self.hosts;
+        if self.user || self.system {
+            config.user = self.user;
+        }
+        config.unit = self.unit.map(|unit| if unit.contains('.') { unit } else { format!("{unit}.service") });
+        if let Some(state) = self.state {
+            config.state = state;
+        }
+        if self.all || self.services {
+            config.all = self.all;
+        }
+        config.view = self.view;
+        if self.theme.is_some() {
+            config.theme = self.theme;
         }
     }
 }
 
+/// Leaves the terminal as it was, releasing the mouse when it was captured.
+fn restore_terminal() {
+    let _ = execute!(std::io::stdout(), DisableMouseCapture);
+    ratatui::restore();
+}
+
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     let terminal = ratatui::init();
-    let args: Config = match Args::try_parse() {
-        Ok(args) => args.into(),
+    let args = match Args::try_parse() {
+        Ok(args) => args,
         Err(err) => {
-            ratatui::restore();
+            restore_terminal();
             err.exit(); 
         }
     };
-    
+    let mut config = match FileConfig::load().and_then(Config::new) {
+        Ok(config) => config,
+        Err(message) => {
+            restore_terminal();
+            return Err(color_eyre::eyre::eyre!(message));
+        }
+    };
+    args.apply(&mut config);
+    match config.theme() {
+        Ok(theme) => set_theme(theme),
+        Err(message) => {
+            restore_terminal();
+            return Err(color_eyre::eyre::eyre!(message));
+        }
+    }
+
+    if config.mouse && let Err(err) = execute!(std::io::stdout(), EnableMouseCapture) {
+        restore_terminal();
+        return Err(err.into());
+    }
+
     let (event_tx, event_rx) = mpsc::channel::<AppEvent>();
 
-    start_notifier();
-    let systemd_adapter = SystemdServiceAdapter::new(ConnectionType::System)?;
+    start_notifier(&config.notifier);
+    let connection_type = match config.hosts.first() {
+        Some(host) => ConnectionType::Remote(host.clone()),
+        None if config.user => ConnectionType::Session,
+        None => ConnectionType::System,
+    };
+    let systemd_adapter = match SystemdServiceAdapter::new(connection_type) {
+        Ok(adapter) => adapter,
+        Err(err) => {
+            restore_terminal();
+            return Err(err.into());
+        }
+    };
     let usecase = Rc::new(RefCell::new(ServicesManager::new(Box::new(
         systemd_adapter
     ))));
     let table_services = TableServices::new(event_tx.clone(), usecase.clone());
-    let filter = Filter::new(event_tx.clone(), args.filter.clone());
+    let filter = Filter::new(event_tx.clone(), config.filter.clone(), config.keymap.clone());
     let service_log = ServiceLog::new(event_tx.clone(), usecase.clone());
     let details = ServiceDetails::new(event_tx.clone(), usecase.clone());
 
@@ -73,8 +160,8 @@ fn main() -> color_eyre::Result<()> {
         details,
         usecase,
     );
-    app.init(args);
+    app.init(config);
     let result = app.run(terminal);
-    ratatui::restore();
+    restore_terminal();
     result
 }
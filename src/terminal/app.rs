@@ -1,63 +1,108 @@
 use color_eyre::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
 use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, LeaveAlternateScreen, EnterAlternateScreen},
 };
 use std::{io::{self}, process::Command};
-use ratatui::layout::{Alignment, Constraint, Margin, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::layout::{Alignment, Constraint, Margin, Layout, Position, Rect};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph, Tabs, Padding};
 use ratatui::DefaultTerminal;
 use ratatui::Frame;
 use std::sync::mpsc::{Receiver, Sender};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::cell::RefCell;
 use std::rc::Rc;
-use rayon::prelude::*;
 
-use crate::infrastructure::systemd_service_adapter::ConnectionType;
+use crate::domain::service::Service;
+use crate::infrastructure::systemd_service_adapter::{ConnectionType, SystemdServiceAdapter};
+use crate::terminal::tabs::{default_tabs, UnitTab};
 use crate::terminal::components::list::ActiveFilterState;
-use crate::usecases::services_manager::ServicesManager;
-use crate::Config;
-
-use super::components::details::ServiceDetails;
+use crate::usecases::services_manager::{RepositoryHandle, ServicesManager};
+use crate::config::{Config, StartView};
+use crate::terminal::mouse::{layout_shortcuts, tab_areas, ClickTracker, MouseAreas, ShortcutEntry};
+use crate::terminal::theme::theme;
+
+use super::components::boot::BootAnalysis;
+use super::components::delta::UnitDeltaView;
+use super::components::details::{DetailsMode, ServiceDetails};
+use super::components::editor::UnitEditor;
 use super::components::filter::{Filter, InputMode};
 use super::components::list::{TableServices, ServiceAction};
+use super::components::hosts::HostSelector;
+use super::keymap::{KeyAction, Keymap};
+use super::components::kill::{KillDialog, KillSubject};
+use super::components::resources::ResourcesDialog;
 use super::components::log::ServiceLog;
-
-#[derive(PartialEq)]
+use super::components::new_unit::NewUnitWizard;
+use super::components::run::RunDialog;
+use super::components::processes::ProcessTree;
+use super::components::status::ServiceStatus;
+use super::components::targets::TargetsView;
+use super::components::jobs::JobsView;
+
+#[derive(Clone, Copy, PartialEq)]
 enum Status {
     List,
     Log,
     Details,
+    NewUnit,
+    Run,
+    Editor,
+    Delta,
+    Processes,
+    Summary,
+    Boot,
+    Targets,
+    Jobs,
 }
 
 pub enum Actions {
     RefreshLog,
     RefreshDetails,
     GoList,
-    ResetList,
     GoLog,
     GoDetails,
+    GoStatus,
+    GoNewUnit,
+    GoRun,
+    GoDelta,
+    GoBoot,
+    GoTargets,
+    GoJobs,
+    RefreshJobs,
+    JobFinished(RepositoryHandle, Result<Service, String>),
+    GoDiff,
+    FocusService(Service),
+    OpenKillDialog,
+    OpenProcessKillDialog(u32),
+    OpenResourcesDialog,
+    OpenHostSelector,
+    SelectHost(ConnectionType),
+    GoProcesses,
+    RefreshProcesses,
     Updatelog((String, String)),
     #[allow(dead_code)]
     UpdateDetails,
     Filter(String),
     UpdateIgnoreListKeys(bool),
     EditCurrentService,
+    OpenEditor,
     ServiceAction(ServiceAction),
     ShowHelp,
     Redraw,
     UpdateTimestamp(String, Option<u64>),
+    UpdateCanReload(String, bool),
 }
 
 pub enum AppEvent {
     Key(KeyEvent),
+    Mouse(MouseEvent),
     Action(Actions),
     Error(String),
 }
@@ -86,11 +131,34 @@ pub struct App {
     filter: Filter,
     service_log: ServiceLog,
     details: ServiceDetails,
+    new_unit: NewUnitWizard,
+    run: RunDialog,
+    editor: UnitEditor,
+    delta: UnitDeltaView,
+    kill: KillDialog,
+    resources: ResourcesDialog,
+    hosts: HostSelector,
+    processes: ProcessTree,
+    unit_status: ServiceStatus,
+    boot: BootAnalysis,
+    targets: TargetsView,
+    jobs: JobsView,
     usecases: Rc<RefCell<ServicesManager>>,
     event_rx: Receiver<AppEvent>,
     event_tx: Sender<AppEvent>,
+    tabs: Vec<UnitTab>,
     selected_tab_index: usize,
     show_help: bool,
+    focused_service: Option<Service>,
+    origin: Status,
+    /// Period of the unit list refresh set in the config file.
+    refresh_interval: Option<Duration>,
+    last_refresh: Instant,
+    keymap: Rc<Keymap>,
+    mouse: bool,
+    /// Where the last frame drew what can be clicked; drawing only borrows the app.
+    mouse_areas: RefCell<MouseAreas>,
+    clicks: ClickTracker,
 }
 
 impl App {
@@ -103,6 +171,19 @@ impl App {
         details: ServiceDetails,
         usecases: Rc<RefCell<ServicesManager>>
     ) -> Self {
+        let new_unit = NewUnitWizard::new(event_tx.clone(), usecases.clone());
+        let run = RunDialog::new(event_tx.clone(), usecases.clone());
+        let editor = UnitEditor::new(event_tx.clone(), usecases.clone());
+        let delta = UnitDeltaView::new(event_tx.clone(), usecases.clone());
+        let kill = KillDialog::new(event_tx.clone(), usecases.clone());
+        let resources = ResourcesDialog::new(event_tx.clone(), usecases.clone());
+        let hosts = HostSelector::new(event_tx.clone());
+        let processes = ProcessTree::new(event_tx.clone(), usecases.clone());
+        let unit_status = ServiceStatus::new(event_tx.clone(), usecases.clone());
+        let boot = BootAnalysis::new(event_tx.clone(), usecases.clone());
+        let targets = TargetsView::new(event_tx.clone(), usecases.clone());
+        let jobs = JobsView::new(event_tx.clone(), usecases.clone());
+
         Self {
             running: true,
             status: Status::List,
@@ -111,20 +192,88 @@ impl App {
             filter,
             service_log,
             details,
+            new_unit,
+            run,
+            editor,
+            delta,
+            kill,
+            resources,
+            hosts,
+            processes,
+            unit_status,
+            boot,
+            targets,
+            jobs,
             usecases,
             event_rx,
             event_tx,
+            tabs: default_tabs(),
             selected_tab_index: 0,
             show_help: false,
+            focused_service: None,
+            origin: Status::List,
+            refresh_interval: None,
+            last_refresh: Instant::now(),
+            keymap: Rc::new(Keymap::default()),
+            mouse: false,
+            mouse_areas: RefCell::new(MouseAreas::default()),
+            clicks: ClickTracker::default(),
         }
     }
 
     pub fn init(&mut self, config: Config) {
+        let first_host = self.tabs.len();
+        self.tabs.extend(config.hosts.iter().map(|host| UnitTab::remote(host)));
+        // main connects to the first host given on the command line.
+        if !config.hosts.is_empty() {
+            self.selected_tab_index = first_host;
+        } else if config.user {
+            self.selected_tab_index = self
+                .tabs
+                .iter()
+                .position(|tab| tab.connection == ConnectionType::Session)
+                .unwrap_or_default();
+        }
+        self.hosts.set_cli_hosts(config.hosts.clone());
+        self.service_log.set_log_lines(config.log_lines);
+        self.refresh_interval = config.refresh_interval;
+        self.keymap = config.keymap.clone();
+        self.unit_status.set_keymap(config.keymap.clone());
+        self.processes.set_keymap(config.keymap.clone());
+        self.targets.set_keymap(config.keymap.clone());
+        self.jobs.set_keymap(config.keymap.clone());
+        self.boot.set_keymap(config.keymap.clone());
+        self.delta.set_keymap(config.keymap.clone());
+        self.details.set_keymap(config.keymap.clone());
+        self.service_log.set_keymap(config.keymap.clone());
+        self.mouse = config.mouse;
         self.table_service.init(&config);
-        self.event_tx.send(AppEvent::Action(Actions::Filter(config.filter))).unwrap();
+        if let Some(unit) = &config.unit {
+            self.open_unit(unit, config.view);
+        }
         self.spawn_key_event_listener();
     }
 
+    /// Selects the unit given with --unit and opens it, focusing it directly when the filters hide it.
+    fn open_unit(&mut self, name: &str, view: StartView) {
+        if !self.table_service.select_service(name) {
+            let result = self.usecases.borrow().get_unit(name);
+            match result {
+                Ok(service) => self.event_tx.send(AppEvent::Action(Actions::FocusService(service))).unwrap(),
+                Err(e) => {
+                    self.event_tx.send(AppEvent::Error(format!("Failed to open {name}: {e}"))).unwrap();
+                    return;
+                }
+            }
+        }
+        let action = match view {
+            StartView::Status => Actions::GoStatus,
+            StartView::Log => Actions::GoLog,
+            StartView::Details => Actions::GoDetails,
+        };
+        self.event_tx.send(AppEvent::Action(action)).unwrap();
+    }
+
     fn spawn_key_event_listener(&self) {
         let event_tx = self.event_tx.clone();
         let event_listener_enabled = self.event_listener_enabled.clone();
@@ -136,13 +285,25 @@ impl App {
                     continue;
                 }
 
-                if event::poll(Duration::from_millis(100)).unwrap_or(false) 
-                    && let Ok(Event::Key(key_event)) = event::read() 
-                        && key_event.kind == KeyEventKind::Press
-                            && event_tx.send(AppEvent::Key(key_event)).is_err()
-                        {
-                            break;
-                        }
+                if !event::poll(Duration::from_millis(100)).unwrap_or(false) {
+                    continue;
+                }
+                let event = match event::read() {
+                    Ok(Event::Key(key_event)) if key_event.kind == KeyEventKind::Press => AppEvent::Key(key_event),
+                    // Moves and drags would only flood the channel.
+                    Ok(Event::Mouse(mouse_event))
+                        if matches!(
+                            mouse_event.kind,
+                            MouseEventKind::Down(_) | MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+                        ) =>
+                    {
+                        AppEvent::Mouse(mouse_event)
+                    }
+                    _ => continue,
+                };
+                if event_tx.send(event).is_err() {
+                    break;
+                }
             }
         });
     }
@@ -151,17 +312,25 @@ impl App {
         self.running = true;
 
         while self.running {
-            match self.status {
-                Status::Log => self.draw_log_status(&mut terminal)?,
-                Status::List => self.draw_list_status(&mut terminal)?,
-                Status::Details => self.draw_details_status(&mut terminal)?,
+            if self.status == Status::List {
+                self.refresh_list_if_due();
             }
 
-            let use_timeout = self.status == Status::List
-                && self.table_service.has_active_runtime();
+            if self.status == Status::List {
+                self.draw_list_status(&mut terminal)?;
+            } else {
+                self.draw_view_status(&mut terminal)?;
+            }
 
-            let event = if use_timeout {
-                match self.event_rx.recv_timeout(Duration::from_secs(1)) {
+            let mut timeout = (self.status == Status::List && self.table_service.has_active_runtime())
+                .then(|| Duration::from_secs(1));
+            if self.status == Status::List && let Some(interval) = self.refresh_interval {
+                let remaining = interval.saturating_sub(self.last_refresh.elapsed());
+                timeout = Some(timeout.map_or(remaining, |timeout| timeout.min(remaining)));
+            }
+
+            let event = if let Some(timeout) = timeout {
+                match self.event_rx.recv_timeout(timeout) {
                     Ok(ev) => ev,
                     Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
                     Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
@@ -189,6 +358,17 @@ impl App {
                             if self.table_service.table_state.selected().is_none() && !self.table_service.is_filtered_list_empty() {
                                 self.table_service.set_selected_index(0);
                             }
+                        } else if self.kill.is_open() {
+                            self.on_key_event(key, &mut terminal)?;
+                            self.kill.on_key_event(key);
+                        } else if self.resources.is_open() {
+                            self.on_key_event(key, &mut terminal)?;
+                            self.resources.on_key_event(key);
+                        } else if self.hosts.is_open() {
+                            self.on_key_event(key, &mut terminal)?;
+                            self.hosts.on_key_event(key);
+                        } else if self.table_service.is_confirming() {
+                            self.table_service.on_key_event(key);
                         } else {
                             self.on_key_event(key, &mut terminal)?;
                             self.on_key_horizontal_event(key, self.filter.input_mode == InputMode::Editing);
@@ -204,9 +384,54 @@ impl App {
                             self.details.on_key_event(key);
                         }
                     }
+                    Status::NewUnit => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.new_unit.on_key_event(key);
+                    }
+                    Status::Run => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.run.on_key_event(key);
+                    }
+                    Status::Editor => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.editor.on_key_event(key);
+                    }
+                    Status::Delta => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.delta.on_key_event(key);
+                    }
+                    Status::Summary => {
+                        if self.show_help {
+                            self.show_help = false;
+                        } else {
+                            self.on_key_event(key, &mut terminal)?;
+                            self.unit_status.on_key_event(key);
+                        }
+                    }
+                    Status::Boot => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.boot.on_key_event(key);
+                    }
+                    Status::Targets => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.targets.on_key_event(key);
+                    }
+                    Status::Jobs => {
+                        self.on_key_event(key, &mut terminal)?;
+                        self.jobs.on_key_event(key);
+                    }
+                    Status::Processes => {
+                        self.on_key_event(key, &mut terminal)?;
+                        if self.kill.is_open() {
+                            self.kill.on_key_event(key);
+                        } else {
+                            self.processes.on_key_event(key);
+                        }
+                    }
                 },
+                AppEvent::Mouse(mouse) => self.on_mouse_event(mouse),
                 AppEvent::Action(Actions::ServiceAction(action)) => {
-                    self.table_service.act_on_selected_service(&action);
+                    self.table_service.request_action(action);
                     self.table_service.invalidate_timestamp();
                 }
                 AppEvent::Action(Actions::UpdateIgnoreListKeys(bool)) => {
@@ -221,7 +446,7 @@ impl App {
                 }
                 AppEvent::Action(Actions::RefreshLog) => {
                     if self.status == Status::Log 
-                        && let Some(service) = self.table_service.get_selected_service() {
+                        && let Some(service) = self.current_service() {
                             self.service_log.fetch_log_and_dispatch(&service);
                     }
                 }
@@ -229,13 +454,85 @@ impl App {
                     self.status = Status::Log;
                     self.event_tx.send(AppEvent::Action(Actions::RefreshLog))?;
                 }
-                AppEvent::Action(Actions::GoList) => self.status = Status::List,
-                AppEvent::Action(Actions::ResetList) => {
-                    self.table_service.set_usecase(self.usecases.clone());
-                },
+                AppEvent::Action(Actions::GoList) => {
+                    self.status = std::mem::replace(&mut self.origin, Status::List);
+                    self.focused_service = None;
+                }
+                AppEvent::Action(Actions::FocusService(service)) => {
+                    self.focused_service = Some(service);
+                    self.origin = self.status;
+                }
+                AppEvent::Action(Actions::GoDelta) => {
+                    self.delta.refresh();
+                    self.status = Status::Delta;
+                }
+                AppEvent::Action(Actions::OpenKillDialog) => {
+                    if let Some(service) = self.current_service() {
+                        self.kill.open(KillSubject::Unit(service));
+                    }
+                }
+                AppEvent::Action(Actions::OpenResourcesDialog) => {
+                    if let Some(service) = self.current_service() {
+                        self.resources.open(service);
+                    }
+                }
+                AppEvent::Action(Actions::OpenHostSelector) => {
+                    let active = self.connection().clone();
+                    self.hosts.open(&active);
+                }
+                AppEvent::Action(Actions::SelectHost(host)) => {
+                    self.select_host(host);
+                }
+                AppEvent::Action(Actions::OpenProcessKillDialog(pid)) => {
+                    self.kill.open(KillSubject::Process(pid));
+                }
+                AppEvent::Action(Actions::GoBoot) => {
+                    self.boot.refresh();
+                    self.status = Status::Boot;
+                }
+                AppEvent::Action(Actions::GoTargets) => {
+                    self.targets.refresh();
+                    self.status = Status::Targets;
+                }
+                AppEvent::Action(Actions::GoJobs) => {
+                    self.jobs.open();
+                    self.status = Status::Jobs;
+                }
+                AppEvent::Action(Actions::RefreshJobs) => {
+                    if self.status == Status::Jobs {
+                        self.jobs.refresh();
+                    }
+                }
+                AppEvent::Action(Actions::JobFinished(repository, result)) => {
+                    self.job_finished(&repository, result);
+                }
+                AppEvent::Action(Actions::GoStatus) => {
+                    if let Some(service) = self.current_service() {
+                        self.unit_status.open(service);
+                        self.status = Status::Summary;
+                    }
+                }
+                AppEvent::Action(Actions::GoProcesses) => {
+                    if let Some(service) = self.current_service() {
+                        self.processes.open(service);
+                        self.status = Status::Processes;
+                    }
+                }
+                AppEvent::Action(Actions::RefreshProcesses) => {
+                    if self.status == Status::Processes {
+                        self.processes.refresh();
+                    }
+                }
+                AppEvent::Action(Actions::GoDiff) => {
+                    self.details.set_mode(DetailsMode::Diff);
+                    self.event_tx.send(AppEvent::Action(Actions::GoDetails))?;
+                }
                 AppEvent::Action(Actions::UpdateTimestamp(name, ts)) => {
                     self.table_service.update_timestamp(name, ts);
                 }
+                AppEvent::Action(Actions::UpdateCanReload(name, can_reload)) => {
+                    self.table_service.update_can_reload(&name, can_reload);
+                }
                 AppEvent::Action(Actions::UpdateDetails | Actions::Redraw) => {}
                 AppEvent::Action(Actions::RefreshDetails) => {
                     if self.status == Status::Details {
@@ -243,15 +540,29 @@ impl App {
                     }
                 }
                 AppEvent::Action(Actions::GoDetails) => {
-                    if let Some(service) = self.table_service.get_selected_service() {
+                    if let Some(service) = self.current_service() {
                         self.details.update(service.clone());
                     }
                     self.event_tx
                         .send(AppEvent::Action(Actions::RefreshDetails))?;
                     self.status = Status::Details;
                 }
+                AppEvent::Action(Actions::GoRun) => {
+                    self.run.open();
+                    self.status = Status::Run;
+                }
+                AppEvent::Action(Actions::GoNewUnit) => {
+                    self.new_unit.open(*self.connection() == ConnectionType::Session);
+                    self.status = Status::NewUnit;
+                }
+                AppEvent::Action(Actions::OpenEditor) => {
+                    if let Some(service) = self.current_service()
+                        && self.editor.open(service) {
+                            self.status = Status::Editor;
+                    }
+                }
                 AppEvent::Action(Actions::EditCurrentService) => {
-                    if let Some(service) = &self.table_service.get_selected_service() {
+                    if let Some(service) = &self.current_service() {
                         self.edit_unit(&mut terminal, service.name())?;
                         self.event_tx.send(AppEvent::Action(Actions::RefreshDetails))?;
                     }
@@ -268,10 +579,17 @@ impl App {
         Ok(())
     }
 
-    #[allow(clippy::unused_self)]
+    /// Service shown by the unit screens: the one focused from another view, or the list selection.
+    fn current_service(&self) -> Option<Service> {
+        self.focused_service.clone().or_else(|| self.table_service.get_selected_service())
+    }
+
     fn resume_tui(&self, terminal: &mut DefaultTerminal) -> Result<()> {
         enable_raw_mode()?;
         execute!(io::stdout(), EnterAlternateScreen)?;
+        if self.mouse {
+            execute!(io::stdout(), EnableMouseCapture)?;
+        }
         terminal.draw(|f| {
             let area = f.area();
             f.render_widget(Clear, area);
@@ -290,7 +608,7 @@ impl App {
         }
 
         let mut stdout = io::stdout();
-        if let Err(e) = execute!(stdout, LeaveAlternateScreen) {
+        if let Err(e) = execute!(stdout, DisableMouseCapture, LeaveAlternateScreen) {
             self.resume_tui(terminal)?;
             self.error_popup(terminal, &format!("Failed to leave alternate screen: {e}"))?;
             return Ok(());
@@ -302,13 +620,20 @@ impl App {
             return Ok(());
         }
 
+        if let ConnectionType::Remote(host) | ConnectionType::Machine(host) = self.connection() {
+            self.resume_tui(terminal)?;
+            self.error_popup(terminal, &format!("'systemctl edit' can not edit units on {host}"))?;
+            self.event_listener_enabled.store(true, Ordering::Relaxed);
+            return Ok(());
+        }
+
         let mut cmd = Command::new("systemctl");
 
         cmd
             .arg("edit")
             .arg("--full");
 
-        if self.selected_tab_index==1{
+        if *self.connection() == ConnectionType::Session {
             cmd
                 .arg("--user");
         }
@@ -342,7 +667,7 @@ impl App {
     #[allow(clippy::unused_self)]
     fn draw_help_popup(&self, frame: &mut Frame, area: Rect) {
         let popup_width = std::cmp::min(80, area.width.saturating_sub(4));
-        let popup_height = std::cmp::min(38, area.height.saturating_sub(4));
+        let popup_height = std::cmp::min(66, area.height.saturating_sub(4));
 
         let popup_x = (area.width.saturating_sub(popup_width)) / 2;
         let popup_y = (area.height.saturating_sub(popup_height)) / 2;
@@ -356,18 +681,17 @@ impl App {
 
         frame.render_widget(Clear, popup_area);
 
-        let text = vec![
+        let heading = Style::default().fg(theme().heading).add_modifier(Modifier::BOLD);
+        let mut text = vec![
             Line::from(vec![Span::styled(
                 "SYSTEMD MANAGER TUI - HELP",
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().accent).add_modifier(Modifier::BOLD),
             )]),
             Line::from(""),
-            Line::from(vec![Span::styled("Navigation:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-            Line::from("↑/k - Move up    ↓/j - Move down"),
-            Line::from("←/h - Previous tab    →/l - Next tab"),
-            Line::from("PageUp/PageDown - Jump 10 items"),
-            Line::from(""),
-            Line::from(vec![Span::styled("Filter:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+        ];
+        text.extend(self.keymap.help_lines());
+        text.extend([
+            Line::from(vec![Span::styled("Filter input:", heading)]),
             Line::from("Ctrl+u - Delete until the start of the input "),
             Line::from("Ctrl+k - Delete until the end of the input "),
             Line::from("Alt+b or Ctrl+← - Go backwards a word "),
@@ -376,37 +700,18 @@ impl App {
             Line::from("Ctrl+a or Home - Go to the end of input  "),
             Line::from("Ctrl+w or Ctrl+Backspace - Delete a word backwards "),
             Line::from(""),
-            Line::from(vec![Span::styled("Service Control:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-            Line::from("s - Start service    x - Stop service"),
-            Line::from("r - Restart service"),
-            Line::from("e - Enable service    d - Disable service"),
-            Line::from("m - Mask/Unmask service"),
-            Line::from(""),
-            Line::from(vec![Span::styled("View & Filter list:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-            Line::from("f - Toggle all/services filter"),
-            Line::from("a - Cycle filter (all→active→inactive→failed)"),
-            Line::from("u - Refresh service list"),
-            Line::from(""),
-            Line::from(vec![Span::styled("Information:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-            Line::from("v - View service logs"),
-            Line::from("c - View unit file details"),
-            Line::from(""),
-            Line::from(vec![Span::styled("Application:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-            Line::from("Ctrl+z - Suspend"),
-            Line::from("Ctrl+c - Quit"),
-            Line::from(""),
             Line::from(vec![Span::styled(
-                "Press ? or any key to close",
-                Style::default().fg(Color::Gray),
+                format!("Press {} or any key to close", self.keymap.keys(KeyAction::Help)),
+                Style::default().fg(theme().muted),
             )]),
-        ];
+        ]);
 
         let help_block = Paragraph::new(text)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(Color::Cyan))
+                    .border_style(Style::default().fg(theme().accent))
                     .padding(Padding::new(1,1,0,0))
                     .title("Help"),
             )
@@ -444,14 +749,14 @@ impl App {
             let text = vec![
                 Line::from(vec![Span::styled(
                     "ERROR",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme().error).add_modifier(Modifier::BOLD),
                 )]),
                 Line::from(""),
                 Line::from(user_friendly_message),
                 Line::from(""),
                 Line::from(vec![Span::styled(
                     "Press any key to dismiss",
-                    Style::default().fg(Color::Gray),
+                    Style::default().fg(theme().muted),
                 )]),
             ];
 
@@ -459,7 +764,7 @@ impl App {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Red))
+                        .border_style(Style::default().fg(theme().error))
                         .title("Error"),
                 )
                 .alignment(Alignment::Center)
@@ -468,41 +773,78 @@ impl App {
             frame.render_widget(error_block, popup_area);
         })?;
 
-        if let Ok(Event::Key(_)) = event::read() {
-            // Continue after key press
+        // Wait for a key press or a click; mouse moves are reported too while the mouse is captured.
+        loop {
+            match event::read() {
+                Ok(Event::Mouse(mouse)) if !matches!(mouse.kind, MouseEventKind::Down(_)) => {}
+                _ => break,
+            }
         }
         Ok(())
     }
 
-    fn draw_details_status(
+    fn draw_view_status(
         &mut self,
         terminal: &mut DefaultTerminal,
     ) -> Result<()> {
         terminal.draw(|frame| {
             let area = frame.area();
 
-            let [list_box, help_area_box] =
+            let [view_box, help_area_box] =
                 Layout::vertical([Constraint::Min(0), Constraint::Max(7)]).areas(area);
 
-            self.details.render(frame, list_box);
-            self.draw_shortcuts(frame, help_area_box, &self.details.shortcuts());
-        })?;
-
-        Ok(())
-    }
-
-    fn draw_log_status(
-        &mut self,
-        terminal: &mut DefaultTerminal,
-    ) -> Result<()> {
-        terminal.draw(|frame| {
-            let area = frame.area();
-
-            let [list_box, help_area_box] =
-                Layout::vertical([Constraint::Min(0), Constraint::Max(7)]).areas(area);
+            let shortcuts = match self.status {
+                Status::List => return,
+                Status::Log => {
+                    self.service_log.render(frame, view_box);
+                    self.service_log.shortcuts()
+                }
+                Status::Details => {
+                    self.details.render(frame, view_box);
+                    self.details.shortcuts()
+                }
+                Status::NewUnit => {
+                    self.new_unit.render(frame, view_box);
+                    self.new_unit.shortcuts()
+                }
+                Status::Run => {
+                    self.run.render(frame, view_box);
+                    self.run.shortcuts()
+                }
+                Status::Editor => {
+                    self.editor.render(frame, view_box);
+                    self.editor.shortcuts()
+                }
+                Status::Delta => {
+                    self.delta.render(frame, view_box);
+                    self.delta.shortcuts()
+                }
+                Status::Processes => {
+                    self.processes.render(frame, view_box);
+                    self.processes.shortcuts()
+                }
+                Status::Summary => {
+                    self.unit_status.render(frame, view_box);
+                    self.unit_status.shortcuts()
+                }
+                Status::Boot => {
+                    self.boot.render(frame, view_box);
+                    self.boot.shortcuts()
+                }
+                Status::Targets => {
+                    self.targets.render(frame, view_box);
+                    self.targets.shortcuts()
+                }
+                Status::Jobs => {
+                    self.jobs.render(frame, view_box);
+                    self.jobs.shortcuts()
+                }
+            };
+            self.draw_shortcuts(frame, help_area_box, &shortcuts);
 
-            self.service_log.render(frame, list_box);
-            self.draw_shortcuts(frame, help_area_box, &self.service_log.shortcuts());
+            if self.status == Status::Processes {
+                self.kill.render(frame, area);
+            }
         })?;
 
         Ok(())
@@ -523,35 +865,15 @@ impl App {
             ])
             .areas(area);
 
-            let filter_state = &self.table_service.get_active_filter_state();
-
-            let system_tab = if self.selected_tab_index == 0 && *filter_state != ActiveFilterState::All {
-                Line::from(vec![
-                    Span::raw("System units"),
-                    Span::styled(
-                        format!(" (Filter: {})", filter_state.as_str()),
-                        Style::default().fg(Color::Gray)
-                    )
-                ])
-            } else {
-                Line::from("System units")
-            };
+            let titles: Vec<Line<'_>> =
+                (0..self.tabs.len()).map(|index| self.tab_title(index, self.tabs[index].label.clone())).collect();
 
-            let session_tab = if self.selected_tab_index == 1 && *filter_state != ActiveFilterState::All{
-                Line::from(vec![
-                    Span::raw("Session units"),
-                    Span::styled(
-                        format!(" (Filter: {})", filter_state.as_str()),
-                        Style::default().fg(Color::Gray)
-                    )
-                ])
-            } else {
-                Line::from("Session units")
-            };
+            self.mouse_areas.borrow_mut().list = list_box;
+            self.mouse_areas.borrow_mut().tabs = tab_areas(&titles, tabs_box);
 
-            let tabs = Tabs::new(vec![system_tab, session_tab])
+            let tabs = Tabs::new(titles)
                 .select(self.selected_tab_index)
-                .highlight_style(Style::default().fg(Color::Yellow));
+                .highlight_style(Style::default().fg(theme().heading));
 
             frame.render_widget(tabs, tabs_box);
 
@@ -565,59 +887,73 @@ impl App {
             if self.show_help {
                 self.draw_help_popup(frame, area);
             }
+            self.kill.render(frame, area);
+            self.resources.render(frame, area);
+            self.hosts.render(frame, area);
         })?;
 
         Ok(())
     }
 
-    #[allow(clippy::unused_self)]
-    fn draw_shortcuts(&self, frame: &mut Frame, help_area: Rect, shortcuts: &[Line<'_>]) {
-        let mut help_text: Vec<Line<'_>> = Vec::new();
-        let shortcuts_lens = shortcuts.len();
+    fn tab_title(&self, index: usize, label: impl Into<String>) -> Line<'static> {
+        let label: String = label.into();
+        if self.selected_tab_index != index {
+            return Line::from(label);
+        }
 
-        help_text.extend(shortcuts.to_owned());
+        let mut spans = vec![Span::raw(label)];
+        let filter_state = self.table_service.get_active_filter_state();
+        if filter_state != ActiveFilterState::All {
+            spans.push(Span::styled(
+                format!(" (Filter: {})", filter_state.as_str()),
+                Style::default().fg(theme().muted)
+            ));
+        }
+        let failed = self.table_service.failed_count();
+        if failed > 0 {
+            spans.push(Span::styled(
+                format!(" [{failed} failed]"),
+                Style::default().fg(theme().error)
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Draws the shortcut bar and remembers where each entry went, so clicking one presses its key.
+    fn draw_shortcuts(&self, frame: &mut Frame, help_area: Rect, shortcuts: &[Line<'_>]) {
+        let block = Block::default().title("Shortcuts").borders(Borders::ALL);
+        let inner = block.inner(help_area);
+        let (mut help_text, mut entries, wrapped) = layout_shortcuts(shortcuts, inner);
 
-        if shortcuts_lens > 0 {
+        if !shortcuts.is_empty() {
             help_text.push(Line::raw(""));
-            let shortcuts_width = shortcuts.to_owned()
-                .par_iter()
-                .map(|line|  line.spans.iter().map(ratatui::prelude::Span::width).sum())
-                .max()
-                .unwrap_or(0);
-            let shortcuts_width = u16::try_from(shortcuts_width).expect("Failed to convert shortcuts_width to u16");
-            if help_area.width > shortcuts_width {
+            if !wrapped {
                 help_text.push(Line::raw(""));
             }
         }
 
+        let exit_keys = format!(": {}", self.keymap.key(KeyAction::Quit));
+        let exit_row = inner.y + u16::try_from(help_text.len()).expect("Failed to convert shortcut rows to u16");
+        if exit_row < inner.bottom() {
+            entries.push(ShortcutEntry::new(inner.x, exit_row, format!("Exit{exit_keys}")));
+        }
         help_text.push(Line::from(vec![
             Span::styled(
                 "Exit",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().error).add_modifier(Modifier::BOLD),
             ),
-            Span::raw(": Ctrl + c"),
+            Span::raw(exit_keys),
         ]));
+        self.mouse_areas.borrow_mut().shortcuts = entries;
 
-        let help_block = Paragraph::new(help_text)
-            .block(Block::default().title("Shortcuts").borders(Borders::ALL))
-            .wrap(ratatui::widgets::Wrap { trim: true });
-
-        frame.render_widget(help_block, help_area);
+        frame.render_widget(Paragraph::new(help_text).block(block), help_area);
     }
 
     fn on_key_event(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) -> Result<()> {
-         if let KeyEvent {
-                 modifiers: KeyModifiers::CONTROL,
-                 code: KeyCode::Char('c' | 'C'),
-                 ..
-             } = key {
+         if self.keymap.is(&key, KeyAction::Quit) {
              self.quit();
          }
-         if let KeyEvent {
-                 modifiers: KeyModifiers::CONTROL,
-                 code: KeyCode::Char('z'),
-                 ..
-             } = key {
+         if self.keymap.is(&key, KeyAction::Suspend) {
              self.suspend_tui(terminal)?;
          }
         Ok(())
@@ -627,7 +963,7 @@ impl App {
         terminal: &mut DefaultTerminal,
     ) -> Result<()> {
         disable_raw_mode()?;
-        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        execute!(terminal.backend_mut(), DisableMouseCapture, LeaveAlternateScreen)?;
         terminal.show_cursor()?;
 
         unsafe {
@@ -639,55 +975,139 @@ impl App {
         Ok(())
     }
 
-    fn on_key_horizontal_event(&mut self, key: KeyEvent, is_filtering: bool) {
-        let left_keys = [KeyCode::Left, KeyCode::Char('h')];
-        let right_keys = [KeyCode::Right, KeyCode::Char('l')];
-        match key {
-            KeyEvent {
-                code,
-                ..
-            } if left_keys.contains(&code) => {
-                if !is_filtering && self. status == Status::List {
-                    self.selected_tab_index = if self.selected_tab_index == 0 {
-                        1
-                    } else {
-                        self.selected_tab_index - 1
-                    };
-
-                    self.update_connection_and_reset();
-                }
+    /// Clicks on shortcut entries press their key; the list, log and details views handle the rest.
+    fn on_mouse_event(&mut self, mouse: MouseEvent) {
+        let position = Position::new(mouse.column, mouse.row);
+        if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+            let key = self.mouse_areas.borrow().shortcut_key_at(position);
+            if let Some(key) = key {
+                self.event_tx.send(AppEvent::Key(key)).expect("Failed to send shortcut key");
+                return;
             }
+        }
+        if self.show_help {
+            return;
+        }
 
-            KeyEvent { code, .. } if right_keys.contains(&code) => {
-                if !is_filtering && self.status == Status::List {
-                    self.selected_tab_index = (self.selected_tab_index + 1) % 2;
-                    self.update_connection_and_reset();
+        match self.status {
+            Status::List => {
+                if self.kill.is_open() || self.resources.is_open() || self.hosts.is_open() {
+                    return;
+                }
+                let tab = self.mouse_areas.borrow().tab_at(position);
+                if let Some(index) = tab
+                    && mouse.kind == MouseEventKind::Down(MouseButton::Left)
+                {
+                    self.switch_tab(index);
+                    return;
+                }
+                let double_click = self.clicks.is_double(&mouse);
+                let list_area = self.mouse_areas.borrow().list;
+                if self.table_service.on_mouse_event(mouse, list_area) && double_click {
+                    self.event_tx.send(AppEvent::Action(Actions::GoDetails)).expect("Failed to open details");
                 }
             }
+            Status::Log => self.service_log.on_mouse_event(mouse),
+            Status::Details => self.details.on_mouse_event(mouse),
+            _ => {}
+        }
+    }
 
+    fn on_key_horizontal_event(&mut self, key: KeyEvent, is_filtering: bool) {
+        if is_filtering || self.status != Status::List {
+            return;
+        }
+        match self.keymap.action(&key) {
+            Some(KeyAction::PreviousTab) => {
+                let len = self.tabs.len();
+                self.switch_tab((self.selected_tab_index + len - 1) % len);
+            }
+            Some(KeyAction::NextTab) => {
+                self.switch_tab((self.selected_tab_index + 1) % self.tabs.len());
+            }
             _ => {}
         }
     }
-    fn update_connection_and_reset(&mut self) {
-        self.table_service.invalidate_timestamp();
+    fn connection(&self) -> &ConnectionType {
+        &self.tabs[self.selected_tab_index].connection
+    }
 
-        let conn_type = match self.selected_tab_index {
-            0 => ConnectionType::System,
-            _ => ConnectionType::Session,
+    /// Shows another tab, connecting it the first time. Returns false when the connection fails,
+    /// leaving the current tab in place.
+    fn switch_tab(&mut self, index: usize) -> bool {
+        if index == self.selected_tab_index {
+            return true;
+        }
+
+        let manager = match self.tabs[index].manager.take() {
+            Some(manager) => manager,
+            None => match SystemdServiceAdapter::new(self.tabs[index].connection.clone()) {
+                Ok(adapter) => ServicesManager::new(Box::new(adapter)),
+                Err(err) => {
+                    let message = match &self.tabs[index].connection {
+                        ConnectionType::Remote(host) => format!("Failed to connect to {host} over ssh: {err}"),
+                        ConnectionType::Machine(machine) => format!("Failed to connect to container {machine}: {err}"),
+                        ConnectionType::System | ConnectionType::Session => {
+                            "Failed to change connection type with D-Bus. Try run without sudo".to_string()
+                        }
+                    };
+                    self.event_tx.send(AppEvent::Error(message)).expect("Failed to change connection type");
+                    return false;
+                }
+            },
         };
 
-        if let Err(_err) = self.usecases
-            .borrow_mut()
-            .change_repository_connection(conn_type)
-        {
-            self.event_tx.send(AppEvent::Error("Failed to change connection type with D-Bus. Try run without sudo".to_string())).expect("Failed to change connection type");
-            self.selected_tab_index = 0;
-            return
+        let previous = std::mem::replace(&mut *self.usecases.borrow_mut(), manager);
+        let current = &mut self.tabs[self.selected_tab_index];
+        current.manager = Some(previous);
+        current.cache = self.table_service.take_cache();
+
+        self.selected_tab_index = index;
+        self.table_service.invalidate_timestamp();
+        self.table_service.restore_cache(std::mem::take(&mut self.tabs[index].cache));
+        self.filter.set_input(self.table_service.filter_text().to_string());
+        true
+    }
+
+    /// Opens the tab of a remote host or container, adding it on first use.
+    fn select_host(&mut self, connection: ConnectionType) {
+        if let Some(index) = self.tabs.iter().position(|tab| tab.connection == connection) {
+            self.switch_tab(index);
+            return;
         }
+        self.tabs.push(UnitTab::for_connection(&connection));
+        if !self.switch_tab(self.tabs.len() - 1) {
+            self.tabs.pop();
+        }
+    }
+
+    /// Applies a finished job to the tab whose bus ran it, which may no longer be the one shown.
+    fn job_finished(&mut self, repository: &RepositoryHandle, result: Result<Service, String>) {
+        if self.usecases.borrow().owns(repository) {
+            self.table_service.job_finished(result);
+            return;
+        }
+        match result {
+            Ok(service) => {
+                if let Some(tab) = self
+                    .tabs
+                    .iter_mut()
+                    .find(|tab| tab.manager.as_ref().is_some_and(|manager| manager.owns(repository)))
+                {
+                    tab.cache.update_service(service);
+                }
+            }
+            Err(message) => self.event_tx.send(AppEvent::Error(message)).expect("Failed to report job error"),
+        }
+    }
 
-        self.event_tx
-            .send(AppEvent::Action(Actions::ResetList))
-            .expect("Failed to send ResetList event");
+    fn refresh_list_if_due(&mut self) {
+        if let Some(interval) = self.refresh_interval
+            && self.last_refresh.elapsed() >= interval
+        {
+            self.table_service.reload();
+            self.last_refresh = Instant::now();
+        }
     }
 
     fn quit(&mut self) {
@@ -0,0 +1,229 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Padding, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::boot_analysis::{blame, critical_chain, format_usec, BlameOrder, BootTimestamps, UnitActivation};
+use crate::domain::service::Service;
+use crate::domain::service_state::ServiceState;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+#[derive(Clone, Copy, PartialEq)]
+enum BootMode {
+    Blame,
+    CriticalChain,
+}
+
+pub struct BootAnalysis {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    timestamps: BootTimestamps,
+    units: Vec<UnitActivation>,
+    default_target: String,
+    mode: BootMode,
+    order: BlameOrder,
+    table_state: TableState,
+    keymap: Rc<Keymap>,
+}
+
+impl BootAnalysis {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            timestamps: BootTimestamps::default(),
+            units: Vec::new(),
+            default_target: String::new(),
+            mode: BootMode::Blame,
+            order: BlameOrder::Duration,
+            table_state: TableState::default(),
+            keymap: Rc::new(Keymap::default()),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
+    pub fn refresh(&mut self) {
+        let usecase = self.usecase.borrow();
+        let result = usecase
+            .boot_timestamps()
+            .and_then(|timestamps| Ok((timestamps, usecase.unit_activations()?)));
+        match result {
+            Ok((timestamps, units)) => {
+                self.timestamps = timestamps;
+                self.units = units;
+                self.default_target = usecase.default_target().unwrap_or_else(|_| "default.target".to_string());
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+        drop(usecase);
+        self.table_state.select((!self.rows().is_empty()).then_some(0));
+    }
+
+    fn rows(&self) -> Vec<(usize, &UnitActivation)> {
+        match self.mode {
+            BootMode::Blame => blame(&self.units, self.order).into_iter().map(|unit| (0, unit)).collect(),
+            BootMode::CriticalChain => critical_chain(&self.units, &self.default_target),
+        }
+    }
+
+    fn select(&mut self, offset: isize) {
+        let len = self.rows().len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select(Some(selected.saturating_add_signed(offset).min(len - 1)));
+    }
+
+    fn open_selected(&self) {
+        let rows = self.rows();
+        let Some((_, unit)) = self.table_state.selected().and_then(|index| rows.get(index)) else {
+            return;
+        };
+        let service = self.usecase.borrow().get_unit(&unit.name).unwrap_or_else(|_| {
+            Service::new(unit.name.clone(), String::new(), ServiceState::default())
+        });
+        self.sender.send(AppEvent::Action(Actions::FocusService(service))).unwrap();
+        self.sender.send(AppEvent::Action(Actions::GoStatus)).unwrap();
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        let keymap = self.keymap.clone();
+        if let Some(offset) = keymap.offset(&key) {
+            self.select(offset);
+        } else if keymap.is_view(&key, ViewAction::NextCategory) {
+            self.mode = match self.mode {
+                BootMode::Blame => BootMode::CriticalChain,
+                BootMode::CriticalChain => BootMode::Blame,
+            };
+            self.table_state.select((!self.rows().is_empty()).then_some(0));
+        } else if keymap.is_view(&key, ViewAction::Sort) {
+            if self.mode == BootMode::Blame {
+                self.order = self.order.next();
+                self.table_state.select((!self.rows().is_empty()).then_some(0));
+            }
+        } else if keymap.is_view(&key, ViewAction::Open) {
+            self.open_selected();
+        } else if keymap.is(&key, KeyAction::Refresh) {
+            self.refresh();
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+        }
+    }
+
+    fn summary(&self) -> Vec<Line<'static>> {
+        if !self.timestamps.is_finished() {
+            return vec![Line::styled(
+                " Bootup is not yet finished, some jobs are still running.",
+                Style::default().fg(theme().warning),
+            )];
+        }
+
+        let mut spans = vec![Span::raw(" Startup finished in ")];
+        for (index, (phase, duration)) in self.timestamps.phases().into_iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::raw(" + "));
+            }
+            spans.push(Span::styled(format_usec(duration), Style::default().fg(theme().accent)));
+            spans.push(Span::raw(format!(" ({phase})")));
+        }
+        spans.push(Span::raw(" = "));
+        spans.push(Span::styled(
+            format_usec(self.timestamps.total()),
+            Style::default().fg(theme().accent).add_modifier(Modifier::BOLD),
+        ));
+
+        let mut lines = vec![Line::from(spans)];
+        if let Some(target) = self.units.iter().find(|unit| unit.name == self.default_target && unit.activated > 0) {
+            lines.push(Line::raw(format!(
+                " {} reached after {} in userspace",
+                target.name,
+                format_usec(target.activated.saturating_sub(self.timestamps.userspace)),
+            )));
+        }
+        lines
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [summary_area, table_area] =
+            Layout::vertical([Constraint::Length(4), Constraint::Min(0)]).areas(area);
+
+        let summary = Paragraph::new(self.summary()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" systemd-analyze ")
+                .title_alignment(Alignment::Center),
+        );
+        frame.render_widget(summary, summary_area);
+
+        let userspace = self.timestamps.userspace;
+        let duration_style = Style::default().fg(theme().warning);
+        let rows: Vec<Row<'_>> = self
+            .rows()
+            .into_iter()
+            .map(|(depth, unit)| {
+                let name = if depth == 0 { unit.name.clone() } else { format!("{}└─{}", "  ".repeat(depth - 1), unit.name) };
+                let duration = if unit.duration() > 0 { format!("+{}", format_usec(unit.duration())) } else { String::new() };
+                Row::new(vec![
+                    Cell::from(duration).style(duration_style),
+                    Cell::from(format!("@{}", format_usec(unit.activated.saturating_sub(userspace))))
+                        .style(Style::default().fg(theme().muted)),
+                    Cell::from(name).style(Style::default().fg(theme().accent).add_modifier(Modifier::BOLD)),
+                ])
+            })
+            .collect();
+
+        let title = match self.mode {
+            BootMode::Blame => format!(" Blame, sorted by {} ", self.order.as_str()),
+            BootMode::CriticalChain => format!(" Critical chain of {} ", self.default_target),
+        };
+        let table = Table::new(rows, [Constraint::Length(16), Constraint::Length(16), Constraint::Min(0)])
+            .header(
+                Row::new(["Duration", "Active at", "Unit"])
+                    .style(Style::default().fg(theme().muted).add_modifier(Modifier::BOLD)),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .padding(Padding::horizontal(1))
+                    .title(title)
+                    .title_alignment(Alignment::Center),
+            )
+            .row_highlight_style(theme().selection())
+            .highlight_symbol(">> ");
+        frame.render_stateful_widget(table, table_area, &mut self.table_state);
+    }
+
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        let mut entries = vec![
+            ("Navigate", keymap.move_keys()),
+            ("Blame/Critical chain", keymap.view_key(ViewAction::NextCategory)),
+        ];
+        if self.mode == BootMode::Blame {
+            entries.push(("Sort", keymap.view_key(ViewAction::Sort)));
+        }
+        entries.extend([
+            ("Unit status", keymap.view_key(ViewAction::Open)),
+            ("Refresh", keymap.key(KeyAction::Refresh)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ]);
+        shortcut_lines(&entries)
+    }
+}
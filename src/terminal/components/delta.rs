@@ -0,0 +1,192 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Padding, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::service_state::ServiceState;
+use crate::domain::unit_diff::{DeltaCategory, UnitOverride};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+fn category_style(category: DeltaCategory) -> Style {
+    match category {
+        DeltaCategory::Masked => Style::default().fg(theme().error),
+        DeltaCategory::Equivalent => Style::default().fg(theme().muted),
+        DeltaCategory::Redirected => Style::default().fg(theme().special),
+        DeltaCategory::Overridden => Style::default().fg(theme().warning),
+        DeltaCategory::Extended => Style::default().fg(theme().accent),
+    }
+}
+
+pub struct UnitDeltaView {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    overrides: Vec<UnitOverride>,
+    category: Option<DeltaCategory>,
+    table_state: TableState,
+    keymap: Rc<Keymap>,
+}
+
+impl UnitDeltaView {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            overrides: Vec::new(),
+            category: None,
+            table_state: TableState::default(),
+            keymap: Rc::new(Keymap::default()),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
+    pub fn refresh(&mut self) {
+        let result = self.usecase.borrow().list_unit_overrides();
+        match result {
+            Ok(overrides) => {
+                self.overrides = overrides;
+                self.table_state.select((!self.filtered().is_empty()).then_some(0));
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    fn filtered(&self) -> Vec<&UnitOverride> {
+        self.overrides
+            .iter()
+            .filter(|unit| self.category.is_none_or(|category| unit.category == category))
+            .collect()
+    }
+
+    fn count(&self, category: DeltaCategory) -> usize {
+        self.overrides.iter().filter(|unit| unit.category == category).count()
+    }
+
+    /// Units with at least one entry; a unit with several drop-ins counts once.
+    fn customized_units(&self) -> usize {
+        self.overrides.iter().map(|unit| unit.name.as_str()).collect::<HashSet<_>>().len()
+    }
+
+    fn cycle_category(&mut self) {
+        self.category = match self.category {
+            None => Some(DeltaCategory::ALL[0]),
+            Some(category) => {
+                let index = DeltaCategory::ALL.iter().position(|c| *c == category).unwrap_or(0);
+                DeltaCategory::ALL.get(index + 1).copied()
+            }
+        };
+        self.table_state.select((!self.filtered().is_empty()).then_some(0));
+    }
+
+    fn select(&mut self, offset: isize) {
+        let len = self.filtered().len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        let next = selected.cast_signed() + offset;
+        self.table_state.select(Some(next.rem_euclid(len.cast_signed()).cast_unsigned()));
+    }
+
+    fn open_selected(&self) {
+        let Some(unit) = self.table_state.selected().and_then(|index| self.filtered().get(index).copied()) else {
+            return;
+        };
+        let service = self.usecase.borrow().get_unit(&unit.name).unwrap_or_else(|_| {
+            Service::new(unit.name.clone(), String::new(), ServiceState::default())
+        });
+        self.sender.send(AppEvent::Action(Actions::FocusService(service))).unwrap();
+        self.sender.send(AppEvent::Action(Actions::GoDiff)).unwrap();
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        let keymap = self.keymap.clone();
+        if let Some(offset) = keymap.offset(&key) {
+            self.select(offset);
+        } else if keymap.is_view(&key, ViewAction::NextCategory) {
+            self.cycle_category();
+        } else if keymap.is_view(&key, ViewAction::Open) {
+            self.open_selected();
+        } else if keymap.is(&key, KeyAction::Refresh) {
+            self.refresh();
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [summary_area, table_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let mut summary = vec![Span::styled(
+            format!(" {} units customized  ", self.customized_units()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        for category in DeltaCategory::ALL {
+            let mut style = category_style(category);
+            if self.category == Some(category) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            summary.push(Span::styled(format!("[{}] {}", category.as_str(), self.count(category)), style));
+            summary.push(Span::raw("  "));
+        }
+        let summary = Paragraph::new(Line::from(summary)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" systemd-delta ")
+                .title_alignment(Alignment::Center),
+        );
+        frame.render_widget(summary, summary_area);
+
+        let rows: Vec<Row<'_>> = self
+            .filtered()
+            .into_iter()
+            .map(|unit| {
+                Row::new(vec![
+                    Cell::from(format!("[{}]", unit.category.as_str())).style(category_style(unit.category)),
+                    Cell::from(unit.name.clone()).style(Style::default().fg(theme().accent).add_modifier(Modifier::BOLD)),
+                    Cell::from(format!("{} → {}", unit.path, unit.target.as_deref().unwrap_or("-")))
+                        .style(Style::default().fg(theme().muted)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows, [Constraint::Length(13), Constraint::Percentage(25), Constraint::Min(0)])
+            .header(
+                Row::new(["Category", "Unit", "Files"])
+                    .style(Style::default().fg(theme().muted).add_modifier(Modifier::BOLD)),
+            )
+            .block(Block::default().borders(Borders::ALL).padding(Padding::horizontal(1)))
+            .row_highlight_style(theme().selection())
+            .highlight_symbol(">> ");
+        frame.render_stateful_widget(table, table_area, &mut self.table_state);
+    }
+
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        shortcut_lines(&[
+            ("Navigate", keymap.move_keys()),
+            ("Cycle category", keymap.view_key(ViewAction::NextCategory)),
+            ("Show diff", keymap.view_key(ViewAction::Open)),
+            ("Refresh", keymap.key(KeyAction::Refresh)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
+    }
+}
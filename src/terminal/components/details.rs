@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
@@ -10,18 +10,72 @@ use std::sync::{Arc, Mutex};
 use std::rc::Rc;
 use std::cell::RefCell;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
 
 use crate::domain::service::Service;
+use crate::domain::unit_diff::{DiffKind, DiffLine, UnitDelta};
+use crate::domain::unit_security::SecurityReport;
 use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::list::security_rating_color;
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
 use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::mouse::WHEEL_LINES;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+pub fn styled_unit_file_line(line: &str) -> Line<'_> {
+    let content = line.trim();
+
+    if content.is_empty() {
+        Line::raw(line)
+    } else if content.starts_with('#') || content.starts_with(';') {
+        Line::styled(line, Style::default().fg(theme().comment))
+    } else if content.starts_with('[') && content.ends_with(']') {
+        Line::styled(line, Style::default().fg(theme().section))
+    } else if let Some((key, value)) = line.split_once('=') {
+        Line::from(vec![
+            Span::styled(format!("{key}="), Style::default().fg(theme().directive)),
+            Span::raw(value),
+        ])
+    } else {
+        Line::styled(line, Style::default())
+    }
+}
+
+pub fn styled_unit_file_lines(unit_file: &str) -> Vec<Line<'_>> {
+    unit_file
+        .lines()
+        .map(|line| styled_unit_file_line(line.trim()))
+        .collect()
+}
+
+fn styled_diff_line(line: &DiffLine) -> Line<'static> {
+    let style = match line.kind {
+        DiffKind::Hunk => Style::default().fg(theme().accent),
+        DiffKind::Added => Style::default().fg(theme().success),
+        DiffKind::Removed => Style::default().fg(theme().error),
+        DiffKind::Context => Style::default(),
+    };
+    Line::styled(line.text.clone(), style)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DetailsMode {
+    UnitFile,
+    Diff,
+    Security,
+}
 
 pub struct ServiceDetails {
     service: Option<Arc<Mutex<Service>>>,
     unit_file: String,
+    mode: DetailsMode,
+    delta: Option<UnitDelta>,
+    security: Option<SecurityReport>,
     sender: Sender<AppEvent>,
     scroll: u16,
     usecase: Rc<RefCell<ServicesManager>>,
+    keymap: Rc<Keymap>,
 }
 
 impl ServiceDetails {
@@ -30,20 +84,32 @@ impl ServiceDetails {
             service: None,
             sender,
             unit_file: String::new(),
+            mode: DetailsMode::UnitFile,
+            delta: None,
+            security: None,
             scroll: 0,
-            usecase
+            usecase,
+            keymap: Rc::new(Keymap::default()),
         }
     }
 
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
         if let Some(service_arc) = &self.service {
             let service = service_arc.lock().unwrap();
-            let paragraph = self.generate_styled_unit_file_paragraph();
+            let (paragraph, title) = match self.mode {
+                DetailsMode::UnitFile => (self.generate_styled_unit_file_paragraph(), format!(" {} file ", service.name())),
+                DetailsMode::Diff => (self.generate_diff_paragraph(), format!(" {} changes against vendor unit ", service.name())),
+                DetailsMode::Security => (self.generate_security_paragraph(), format!(" {} security exposure ", service.name())),
+            };
             let paragraph = paragraph
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title(format!(" {} file ", service.name()))
+                        .title(title)
                         .title_alignment(Alignment::Center),
                 )
                 .scroll((self.scroll, 0))
@@ -54,84 +120,141 @@ impl ServiceDetails {
     }
 
     fn generate_styled_unit_file_paragraph(&self) -> Paragraph<'_> {
-        let mut lines: Vec<Line<'_>> = vec![];
-        for line in self.unit_file.lines() {
-            let line = line.trim();
-
-            if line.is_empty() {
-                lines.push(Line::raw(""));
-            } else if line.starts_with('#') || line.starts_with(';') {
-                lines.push(Line::styled(line, Style::default().fg(Color::Green)));
-            } else if line.starts_with('[') && line.ends_with(']') {
-                lines.push(Line::styled(line, Style::default().fg(Color::LightBlue)));
-            } else if let Some((key, value)) = line.split_once('=') {
-                lines.push(Line::from(vec![
-                    Span::styled(format!("{key}="), Style::default().fg(Color::Yellow)),
-                    Span::raw(value),
-                ]));
-            } else {
-                lines.push(Line::styled(line, Style::default()));
+        Paragraph::new(styled_unit_file_lines(&self.unit_file))
+    }
+
+    fn generate_diff_paragraph(&self) -> Paragraph<'_> {
+        let Some(delta) = &self.delta else {
+            return Paragraph::new("Loading...");
+        };
+
+        let header_style = Style::default().add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::styled(
+                format!("--- {}", delta.vendor_path.as_deref().unwrap_or("(no vendor unit)")),
+                header_style,
+            ),
+            Line::styled(format!("+++ {}", delta.fragment_path), header_style),
+        ];
+        lines.extend(delta.drop_in_paths.iter().map(|path| {
+            Line::styled(format!("+++ {path} (drop-in)"), header_style)
+        }));
+        lines.push(Line::raw(""));
+
+        if !delta.is_customized() {
+            lines.push(Line::styled("Unit is not customized, it matches the vendor unit.", Style::default().fg(theme().muted)));
+        } else {
+            let diff = delta.diff();
+            if diff.is_empty() {
+                lines.push(Line::styled("Override is equivalent to the vendor unit.", Style::default().fg(theme().muted)));
             }
+            lines.extend(diff.iter().map(styled_diff_line));
         }
+
+        Paragraph::new(lines)
+    }
+
+    fn generate_security_paragraph(&self) -> Paragraph<'_> {
+        let Some(report) = &self.security else {
+            return Paragraph::new("Loading...");
+        };
+
+        let rating = report.rating();
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Overall exposure level: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!("{:.1} {}", report.exposure(), rating.as_str()),
+                    Style::default().fg(security_rating_color(rating)).add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::raw(""),
+        ];
+
+        let total_weight = report.total_weight();
+        let mut checks: Vec<_> = report.checks.iter().collect();
+        checks.sort_by(|a, b| b.exposure(total_weight).total_cmp(&a.exposure(total_weight)).then(a.directive.cmp(b.directive)));
+        lines.extend(checks.into_iter().map(|check| {
+            let (mark, style) = if check.passed() {
+                ("✓ ", Style::default().fg(theme().success))
+            } else {
+                ("✗ ", Style::default().fg(theme().error))
+            };
+            let exposure = if check.passed() { String::new() } else { format!("{:.1}", check.exposure(total_weight)) };
+            Line::from(vec![
+                Span::styled(mark, style),
+                Span::styled(format!("{:<48}", check.directive), Style::default().fg(theme().directive)),
+                Span::raw(format!("{:<62}", check.description)),
+                Span::styled(exposure, Style::default().fg(theme().muted)),
+            ])
+        }));
+
         Paragraph::new(lines)
     }
 
     pub fn on_key_event(&mut self, key: KeyEvent) {
-        let right_keys = [KeyCode::Right, KeyCode::Char('l')];
-        let left_keys = [KeyCode::Left, KeyCode::Char('h')];
-        let up_keys = [KeyCode::Up, KeyCode::Char('k')];
-        let down_keys = [KeyCode::Down, KeyCode::Char('j')];
-
-        match key.code {
-            code if right_keys.contains(&code) => {
-                self.reset();
-                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
-            }
-            code if left_keys.contains(&code) => {
-                self.reset();
-                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
-            }
-            code if up_keys.contains(&code) => {
-                self.scroll = self.scroll.saturating_sub(1);
-            }
-            code if down_keys.contains(&code) => {
-                self.scroll += 1;
-            }
-            KeyCode::PageUp => {
-                self.scroll = self.scroll.saturating_sub(10);
-            }
-            KeyCode::PageDown => {
-                self.scroll += 10;
-            }
-            KeyCode::Char('e') => {
-                self.sender.send(AppEvent::Action(Actions::EditCurrentService)).unwrap();
-            }
-            KeyCode::Char('q') | KeyCode::Esc => {
-                self.exit();
-            }
-            _ => {}
+        let keymap = self.keymap.clone();
+        if keymap.is(&key, KeyAction::NextTab) || keymap.is(&key, KeyAction::PreviousTab) {
+            self.reset();
+            self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+        } else if keymap.is(&key, KeyAction::MoveUp) {
+            self.scroll = self.scroll.saturating_sub(1);
+        } else if keymap.is(&key, KeyAction::MoveDown) {
+            self.scroll += 1;
+        } else if keymap.is(&key, KeyAction::PageUp) {
+            self.scroll = self.scroll.saturating_sub(10);
+        } else if keymap.is(&key, KeyAction::PageDown) {
+            self.scroll += 10;
+        } else if keymap.is_view(&key, ViewAction::Edit) {
+            self.sender.send(AppEvent::Action(Actions::OpenEditor)).unwrap();
+        } else if keymap.is_view(&key, ViewAction::EditExternal) {
+            self.sender.send(AppEvent::Action(Actions::EditCurrentService)).unwrap();
+        } else if keymap.is_view(&key, ViewAction::Diff) {
+            let mode = if self.mode == DetailsMode::Diff { DetailsMode::UnitFile } else { DetailsMode::Diff };
+            self.set_mode(mode);
+            self.sender.send(AppEvent::Action(Actions::RefreshDetails)).unwrap();
+        } else if keymap.is_view(&key, ViewAction::Security) {
+            let mode = if self.mode == DetailsMode::Security { DetailsMode::UnitFile } else { DetailsMode::Security };
+            self.set_mode(mode);
+            self.sender.send(AppEvent::Action(Actions::RefreshDetails)).unwrap();
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.exit();
         }
     }
     
     #[allow(clippy::unused_self)]
-    pub fn shortcuts(&self) -> Vec<Line<'_>> {
-        let help_text = vec![
-            Line::from(vec![Span::styled(
-                "Actions",
-                Style::default()
-                    .fg(Color::LightMagenta)
-                    .add_modifier(Modifier::BOLD),
-            )]),
-            Line::from("Switch tabs: ←/→ | Edit: e | Go back: q/Esc"),
-        ];
+    pub fn on_mouse_event(&mut self, mouse: MouseEvent) {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.scroll = self.scroll.saturating_sub(WHEEL_LINES),
+            MouseEventKind::ScrollDown => self.scroll = self.scroll.saturating_add(WHEEL_LINES),
+            _ => {}
+        }
+    }
 
-        help_text
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        shortcut_lines(&[
+            ("Switch tabs", keymap.pane_keys()),
+            ("Edit", keymap.view_key(ViewAction::Edit)),
+            ("Edit with $EDITOR", keymap.view_key(ViewAction::EditExternal)),
+            ("Diff against vendor", keymap.view_key(ViewAction::Diff)),
+            ("Security", keymap.view_key(ViewAction::Security)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
     }
 
     pub fn reset(&mut self) {
         self.service = None;
         self.scroll = 0;
         self.unit_file = String::new();
+        self.mode = DetailsMode::UnitFile;
+        self.delta = None;
+        self.security = None;
+    }
+
+    pub fn set_mode(&mut self, mode: DetailsMode) {
+        self.mode = mode;
+        self.scroll = 0;
     }
 
     fn exit(&mut self) {
@@ -145,6 +268,28 @@ impl ServiceDetails {
         if let Some(service_arc) = maybe_service {
             let service = service_arc.lock().unwrap();
 
+            if self.mode == DetailsMode::Diff {
+                let result = self.usecase.borrow().unit_delta(&service);
+                match result {
+                    Ok(delta) => self.delta = Some(delta),
+                    Err(e) => {
+                        self.set_mode(DetailsMode::UnitFile);
+                        self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+                    }
+                }
+            }
+
+            if self.mode == DetailsMode::Security {
+                let result = self.usecase.borrow().security_report(&service);
+                match result {
+                    Ok(report) => self.security = Some(report),
+                    Err(e) => {
+                        self.set_mode(DetailsMode::UnitFile);
+                        self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+                    }
+                }
+            }
+
             let result = self.usecase.borrow().systemctl_cat(&service);
 
             match result {
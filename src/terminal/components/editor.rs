@@ -0,0 +1,345 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::unit_directives::{validate_unit_file, Diagnostic};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::details::styled_unit_file_line;
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+fn byte_index(line: &str, col: usize) -> usize {
+    line.char_indices().nth(col).map_or(line.len(), |(i, _)| i)
+}
+
+pub struct UnitEditor {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    service: Option<Service>,
+    lines: Vec<String>,
+    original: String,
+    row: usize,
+    col: usize,
+    top: usize,
+    left: usize,
+    height: usize,
+    diagnostics: Vec<Diagnostic>,
+    verify_diagnostics: Vec<Diagnostic>,
+    confirm_save: bool,
+    confirm_discard: bool,
+    message: Option<String>,
+}
+
+impl UnitEditor {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            service: None,
+            lines: vec![String::new()],
+            original: String::new(),
+            row: 0,
+            col: 0,
+            top: 0,
+            left: 0,
+            height: 0,
+            diagnostics: Vec::new(),
+            verify_diagnostics: Vec::new(),
+            confirm_save: false,
+            confirm_discard: false,
+            message: None,
+        }
+    }
+
+    /// Loads the unit file of the service. Returns false when the file could not be read.
+    pub fn open(&mut self, service: Service) -> bool {
+        let result = self.usecase.borrow().load_unit_file(&service);
+        match result {
+            Ok(content) => {
+                let sender = self.sender.clone();
+                let usecase = self.usecase.clone();
+                *self = Self::new(sender, usecase);
+                self.lines = content.lines().map(str::to_string).collect();
+                if self.lines.is_empty() {
+                    self.lines.push(String::new());
+                }
+                self.original = self.content();
+                self.service = Some(service);
+                self.validate();
+                true
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+                false
+            }
+        }
+    }
+
+    fn content(&self) -> String {
+        let mut content = self.lines.join("\n");
+        content.push('\n');
+        content
+    }
+
+    fn is_modified(&self) -> bool {
+        self.content() != self.original
+    }
+
+    fn validate(&mut self) {
+        if let Some(service) = &self.service {
+            self.diagnostics = validate_unit_file(service.name(), &self.content());
+        }
+    }
+
+    fn edited(&mut self) {
+        self.confirm_save = false;
+        self.confirm_discard = false;
+        self.verify_diagnostics.clear();
+        self.validate();
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    fn insert_char(&mut self, c: char) {
+        let index = byte_index(&self.lines[self.row], self.col);
+        self.lines[self.row].insert(index, c);
+        self.col += 1;
+        self.edited();
+    }
+
+    fn insert_newline(&mut self) {
+        let index = byte_index(&self.lines[self.row], self.col);
+        let rest = self.lines[self.row].split_off(index);
+        self.lines.insert(self.row + 1, rest);
+        self.row += 1;
+        self.col = 0;
+        self.edited();
+    }
+
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let index = byte_index(&self.lines[self.row], self.col);
+            self.lines[self.row].remove(index);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len();
+            self.lines[self.row].push_str(&line);
+        } else {
+            return;
+        }
+        self.edited();
+    }
+
+    fn delete(&mut self) {
+        if self.col < self.line_len() {
+            let index = byte_index(&self.lines[self.row], self.col);
+            self.lines[self.row].remove(index);
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&line);
+        } else {
+            return;
+        }
+        self.edited();
+    }
+
+    fn move_vertical(&mut self, rows: isize) {
+        let last = self.lines.len() - 1;
+        self.row = self.row.saturating_add_signed(rows).min(last);
+        self.col = self.col.min(self.line_len());
+    }
+
+    fn save(&mut self) {
+        let Some(service) = self.service.clone() else {
+            return;
+        };
+        let content = self.content();
+
+        if !self.confirm_save {
+            self.verify_diagnostics = match self.usecase.borrow().verify_unit_file(&service, &content) {
+                Ok(diagnostics) => diagnostics,
+                Err(e) => vec![Diagnostic { line: None, message: format!("systemd-analyze verify failed: {e}") }],
+            };
+
+            let problems = self.diagnostics.len() + self.verify_diagnostics.len();
+            if problems > 0 {
+                self.confirm_save = true;
+                self.message = Some(format!("{problems} problem(s) found. Press Ctrl+s again to save anyway."));
+                return;
+            }
+        }
+
+        let result = self.usecase.borrow().save_unit_file(&service, &content);
+        match result {
+            Ok(_) => self.exit(),
+            Err(e) => {
+                self.confirm_save = false;
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    fn exit(&mut self) {
+        self.service = None;
+        self.sender.send(AppEvent::Action(Actions::GoDetails)).unwrap();
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        self.message = None;
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Char('s') if control => self.save(),
+            KeyCode::Esc => {
+                if self.is_modified() && !self.confirm_discard {
+                    self.confirm_discard = true;
+                    self.message = Some("Unsaved changes. Press Esc again to discard them.".to_string());
+                } else {
+                    self.exit();
+                }
+            }
+            KeyCode::Char(_) if control => {}
+            KeyCode::Char(c) => self.insert_char(c),
+            KeyCode::Enter => self.insert_newline(),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Up => self.move_vertical(-1),
+            KeyCode::Down => self.move_vertical(1),
+            KeyCode::PageUp => self.move_vertical(-(self.height.max(1).cast_signed())),
+            KeyCode::PageDown => self.move_vertical(self.height.max(1).cast_signed()),
+            KeyCode::Left => {
+                if self.col > 0 {
+                    self.col -= 1;
+                } else if self.row > 0 {
+                    self.row -= 1;
+                    self.col = self.line_len();
+                }
+            }
+            KeyCode::Right => {
+                if self.col < self.line_len() {
+                    self.col += 1;
+                } else if self.row + 1 < self.lines.len() {
+                    self.row += 1;
+                    self.col = 0;
+                }
+            }
+            KeyCode::Home => self.col = 0,
+            KeyCode::End => self.col = self.line_len(),
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(service) = &self.service else {
+            return;
+        };
+
+        let problems: Vec<&Diagnostic> = self.diagnostics.iter().chain(self.verify_diagnostics.iter()).collect();
+        let panel_height = u16::try_from(problems.len() + usize::from(self.message.is_some()))
+            .unwrap_or(u16::MAX)
+            .saturating_add(2)
+            .min(10);
+        let [editor_area, diagnostics_area] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(panel_height)]).areas(area);
+
+        let modified = if self.is_modified() { " [modified]" } else { "" };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Editing {}{modified} ", service.name()))
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(editor_area);
+        frame.render_widget(block, editor_area);
+
+        let gutter_width = self.lines.len().to_string().len() + 1;
+        let [gutter_area, text_area] = Layout::horizontal([
+            Constraint::Length(u16::try_from(gutter_width + 1).unwrap_or(u16::MAX)),
+            Constraint::Min(0),
+        ])
+        .areas(inner);
+
+        self.height = text_area.height as usize;
+        let width = text_area.width as usize;
+        if self.row < self.top {
+            self.top = self.row;
+        } else if self.height > 0 && self.row >= self.top + self.height {
+            self.top = self.row + 1 - self.height;
+        }
+        if self.col < self.left {
+            self.left = self.col;
+        } else if width > 0 && self.col >= self.left + width {
+            self.left = self.col + 1 - width;
+        }
+
+        let visible = self.top..(self.top + self.height).min(self.lines.len());
+        let gutter: Vec<Line<'_>> = visible
+            .clone()
+            .map(|index| {
+                let style = if problems.iter().any(|d| d.line == Some(index)) {
+                    Style::default().fg(theme().error).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme().dim)
+                };
+                Line::styled(format!("{:>gutter_width$}", index + 1), style)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(gutter), gutter_area);
+
+        let text: Vec<Line<'_>> = self.lines[visible]
+            .iter()
+            .map(|line| styled_unit_file_line(line))
+            .collect();
+        #[allow(clippy::cast_possible_truncation)]
+        frame.render_widget(Paragraph::new(text).scroll((0, self.left as u16)), text_area);
+
+        #[allow(clippy::cast_possible_truncation)]
+        frame.set_cursor_position(Position::new(
+            text_area.x + (self.col - self.left) as u16,
+            text_area.y + (self.row - self.top) as u16,
+        ));
+
+        let mut lines: Vec<Line<'_>> = problems
+            .iter()
+            .map(|diagnostic| {
+                let location = diagnostic.line.map(|line| format!("Line {}: ", line + 1)).unwrap_or_default();
+                Line::from(vec![
+                    Span::styled(location, Style::default().fg(theme().error).add_modifier(Modifier::BOLD)),
+                    Span::raw(diagnostic.message.clone()),
+                ])
+            })
+            .collect();
+        if let Some(message) = &self.message {
+            lines.push(Line::styled(message.clone(), Style::default().fg(theme().warning)));
+        }
+
+        let title = if problems.is_empty() { " No problems found " } else { " Diagnostics " };
+        let panel = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(panel, diagnostics_area);
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        shortcut_lines(&[
+            ("Move", "←/↑/↓/→".to_string()),
+            ("Page", "PgUp/PgDn".to_string()),
+            ("Line start/end", "Home/End".to_string()),
+            ("Verify and save", "Ctrl+s".to_string()),
+            ("Close", "Esc".to_string()),
+        ])
+    }
+}
@@ -2,20 +2,24 @@ use crossterm::event::KeyModifiers;
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, KeyEventKind},
     layout::{Constraint, Layout, Position, Rect},
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Text},
     widgets::{Block, Paragraph},
     Frame,
 };
+use std::rc::Rc;
 use std::sync::mpsc::Sender;
 
 use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::keymap::{KeyAction, Keymap};
+use crate::terminal::theme::theme;
 
 pub struct Filter {
     pub input: String,
     character_index: usize,
     pub input_mode: InputMode,
     sender: Sender<AppEvent>,
+    keymap: Rc<Keymap>,
 }
 
 #[derive(PartialEq)]
@@ -25,16 +29,24 @@ pub enum InputMode {
 }
 
 impl Filter {
-    pub const fn new(sender: Sender<AppEvent>, input: String) -> Self {
+    pub fn new(sender: Sender<AppEvent>, input: String, keymap: Rc<Keymap>) -> Self {
         let character_index = input.len();
         Self {
             sender,
             input,
             input_mode: InputMode::Normal,
             character_index,
+            keymap,
         }
     }
 
+    /// Replaces the text without submitting it, for a list whose filter is already applied.
+    pub fn set_input(&mut self, input: String) {
+        self.character_index = input.chars().count();
+        self.input = input;
+        self.input_mode = InputMode::Normal;
+    }
+
     fn move_cursor_left(&mut self) {
         let cursor_moved_left = self.character_index.saturating_sub(1);
         self.character_index = self.clamp_cursor(cursor_moved_left);
@@ -179,7 +191,7 @@ impl Filter {
     pub fn on_key_event(&mut self, key: KeyEvent) {
         match self.input_mode {
             InputMode::Normal => match key.code {
-                KeyCode::Char('i') | KeyCode::Char('/') => {
+                _ if self.keymap.is(&key, KeyAction::Filter) => {
                     self.sender
                         .send(AppEvent::Action(Actions::UpdateIgnoreListKeys(true)))
                         .unwrap();
@@ -265,7 +277,7 @@ impl Filter {
 
         let (msg, style) = match self.input_mode {
             InputMode::Normal => (
-                vec!["Press ".into(), "i".bold(), " or ".into(), "/".bold(), " to start filtering.".into()],
+                vec!["Press ".into(), self.keymap.keys(KeyAction::Filter).bold(), " to start filtering.".into()],
                 Style::default(),
             ),
             InputMode::Editing => (
@@ -286,7 +298,7 @@ impl Filter {
         let input = Paragraph::new(self.input.as_str())
             .style(match self.input_mode {
                 InputMode::Normal => Style::default(),
-                InputMode::Editing => Style::default().fg(Color::Yellow),
+                InputMode::Editing => Style::default().fg(theme().heading),
             })
             .block(Block::bordered().title("Filter"));
         frame.render_widget(input, input_area);
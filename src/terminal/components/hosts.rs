@@ -0,0 +1,137 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::sync::mpsc::Sender;
+
+use crate::infrastructure::machined::running_containers;
+use crate::infrastructure::ssh_config::configured_hosts;
+use crate::infrastructure::systemd_service_adapter::ConnectionType;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::popup::centered_area;
+use crate::terminal::theme::theme;
+
+pub struct HostSelector {
+    sender: Sender<AppEvent>,
+    /// Hosts given with --host, listed before the ones from ~/.ssh/config.
+    cli_hosts: Vec<String>,
+    /// This machine, then ssh hosts, then local containers.
+    hosts: Vec<ConnectionType>,
+    active: ConnectionType,
+    selected: usize,
+    open: bool,
+}
+
+impl HostSelector {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            sender,
+            cli_hosts: Vec::new(),
+            hosts: Vec::new(),
+            active: ConnectionType::System,
+            selected: 0,
+            open: false,
+        }
+    }
+
+    pub fn set_cli_hosts(&mut self, hosts: Vec<String>) {
+        self.cli_hosts = hosts;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self, active: &ConnectionType) {
+        let mut hosts: Vec<String> = self.cli_hosts.clone();
+        for host in configured_hosts() {
+            if !hosts.contains(&host) {
+                hosts.push(host);
+            }
+        }
+        // machined is optional, without it there are simply no containers to offer.
+        let containers = running_containers().unwrap_or_default();
+        self.hosts = std::iter::once(ConnectionType::System)
+            .chain(hosts.into_iter().map(ConnectionType::Remote))
+            .chain(containers.into_iter().map(ConnectionType::Machine))
+            .collect();
+        // The session bus belongs to this machine.
+        self.active = match active {
+            ConnectionType::Session => ConnectionType::System,
+            connection => connection.clone(),
+        };
+        self.selected = self.hosts.iter().position(|host| *host == self.active).unwrap_or(0);
+        self.open = true;
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.open = false,
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(self.hosts.len().saturating_sub(1)),
+            KeyCode::Enter => {
+                self.open = false;
+                if let Some(host) = self.hosts.get(self.selected).filter(|host| **host != self.active) {
+                    self.sender.send(AppEvent::Action(Actions::SelectHost(host.clone()))).unwrap();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let selected_style = Style::default().fg(theme().accent).add_modifier(Modifier::BOLD);
+        let mut lines: Vec<Line<'_>> = self
+            .hosts
+            .iter()
+            .enumerate()
+            .map(|(index, host)| {
+                let marker = if index == self.selected { ">> " } else { "   " };
+                let style = if index == self.selected { selected_style } else { Style::default() };
+                let active = if *host == self.active {
+                    Span::styled(" ● active", Style::default().fg(theme().success))
+                } else {
+                    Span::raw("")
+                };
+                let name = match host {
+                    ConnectionType::Remote(host) => host.clone(),
+                    ConnectionType::Machine(machine) => format!("{machine} (container)"),
+                    ConnectionType::System | ConnectionType::Session => "localhost (this machine)".to_string(),
+                };
+                Line::from(vec![Span::styled(format!("{marker}{name}"), style), active])
+            })
+            .collect();
+        if self.hosts.len() == 1 {
+            lines.push(Line::styled(
+                "   Add hosts with --host or in ~/.ssh/config, or start a container",
+                Style::default().fg(theme().dim),
+            ));
+        }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            "↑/↓: host | Enter: connect | Esc: cancel",
+            Style::default().fg(theme().muted),
+        ));
+
+        let height = u16::try_from(lines.len() + 2).unwrap_or(u16::MAX);
+        let popup_area = centered_area(area, 56, height);
+        frame.render_widget(Clear, popup_area);
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme().accent))
+                .padding(Padding::horizontal(1))
+                .title(" Hosts and containers "),
+        );
+        frame.render_widget(popup, popup_area);
+    }
+}
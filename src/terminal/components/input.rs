@@ -0,0 +1,82 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[derive(Clone, Default)]
+pub struct TextInput {
+    value: String,
+    character_index: usize,
+}
+
+impl TextInput {
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            character_index: value.chars().count(),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.to_string();
+        self.character_index = value.chars().count();
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.character_index
+    }
+
+    fn byte_index(&self) -> usize {
+        self.value
+            .char_indices()
+            .map(|(i, _)| i)
+            .nth(self.character_index)
+            .unwrap_or(self.value.len())
+    }
+
+    /// Applies an editing key to the input. Returns false when the key is not an editing key.
+    pub fn on_key_event(&mut self, key: KeyEvent) -> bool {
+        let len = self.value.chars().count();
+        match key.code {
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.value = self.value.chars().skip(self.character_index).collect();
+                self.character_index = 0;
+            }
+            KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.value = self.value.chars().take(self.character_index).collect();
+            }
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.character_index = 0;
+            }
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.character_index = len;
+            }
+            KeyCode::Char(_) if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(c) => {
+                let index = self.byte_index();
+                self.value.insert(index, c);
+                self.character_index += 1;
+            }
+            KeyCode::Backspace => {
+                if self.character_index > 0 {
+                    self.character_index -= 1;
+                    let index = self.byte_index();
+                    self.value.remove(index);
+                }
+            }
+            KeyCode::Delete => {
+                if self.character_index < len {
+                    let index = self.byte_index();
+                    self.value.remove(index);
+                }
+            }
+            KeyCode::Left => self.character_index = self.character_index.saturating_sub(1),
+            KeyCode::Right => self.character_index = (self.character_index + 1).min(len),
+            KeyCode::Home => self.character_index = 0,
+            KeyCode::End => self.character_index = len,
+            _ => return false,
+        }
+        true
+    }
+}
@@ -0,0 +1,224 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Padding, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::unit_job::UnitJob;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::popup::centered_area;
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+pub struct JobsView {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    jobs: Vec<UnitJob>,
+    table_state: TableState,
+    confirm_clear: bool,
+    keymap: Rc<Keymap>,
+}
+
+impl JobsView {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            jobs: Vec::new(),
+            table_state: TableState::default(),
+            confirm_clear: false,
+            keymap: Rc::new(Keymap::default()),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
+    pub fn open(&mut self) {
+        self.confirm_clear = false;
+        self.refresh();
+        self.watch();
+    }
+
+    pub fn refresh(&mut self) {
+        let result = self.usecase.borrow().list_jobs();
+        match result {
+            Ok(jobs) => {
+                let selected_id = self.selected().map(|job| job.id);
+                self.jobs = jobs;
+                // Keep the selection on the same job; fall back to the same row once it is gone.
+                let previous = self.table_state.selected().unwrap_or(0);
+                let index = selected_id
+                    .and_then(|id| self.jobs.iter().position(|job| job.id == id))
+                    .unwrap_or(previous);
+                self.table_state.select((!self.jobs.is_empty()).then(|| index.min(self.jobs.len() - 1)));
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    fn watch(&mut self) {
+        let sender = self.sender.clone();
+        let on_change = Box::new(move || {
+            let _ = sender.send(AppEvent::Action(Actions::RefreshJobs));
+        });
+        if let Err(e) = self.usecase.borrow().watch_jobs(on_change) {
+            self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+        }
+    }
+
+    fn selected(&self) -> Option<&UnitJob> {
+        self.table_state.selected().and_then(|index| self.jobs.get(index))
+    }
+
+    fn select(&mut self, offset: isize) {
+        let len = self.jobs.len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select(Some(selected.saturating_add_signed(offset).min(len - 1)));
+    }
+
+    fn cancel_selected(&mut self) {
+        let Some(id) = self.selected().map(|job| job.id) else {
+            return;
+        };
+        if let Err(e) = self.usecase.borrow().cancel_job(id) {
+            self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+        }
+        self.refresh();
+    }
+
+    fn clear(&mut self) {
+        self.confirm_clear = false;
+        if let Err(e) = self.usecase.borrow().clear_jobs() {
+            self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+        }
+        self.refresh();
+    }
+
+    fn exit(&mut self) {
+        if let Err(e) = self.usecase.borrow().unwatch_jobs() {
+            self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+        }
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        let keymap = self.keymap.clone();
+        if self.confirm_clear {
+            if keymap.is_view(&key, ViewAction::Confirm) {
+                self.clear();
+            } else if keymap.is_view(&key, ViewAction::Cancel) {
+                self.confirm_clear = false;
+            }
+            return;
+        }
+
+        if let Some(offset) = keymap.offset(&key) {
+            self.select(offset);
+        } else if keymap.is_view(&key, ViewAction::CancelJob) {
+            self.cancel_selected();
+        } else if keymap.is_view(&key, ViewAction::ClearJobs) {
+            self.confirm_clear = !self.jobs.is_empty();
+        } else if keymap.is(&key, KeyAction::Refresh) {
+            self.refresh();
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.exit();
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row<'_>> = self
+            .jobs
+            .iter()
+            .map(|job| {
+                let state_style = match job.state.as_str() {
+                    "running" => Style::default().fg(theme().success),
+                    _ => Style::default().fg(theme().warning),
+                };
+                Row::new(vec![
+                    Cell::from(job.id.to_string()).style(Style::default().fg(theme().muted)),
+                    Cell::from(job.unit.clone()).style(Style::default().fg(theme().accent).add_modifier(Modifier::BOLD)),
+                    Cell::from(job.job_type.clone()),
+                    Cell::from(job.state.clone()).style(state_style),
+                ])
+            })
+            .collect();
+
+        let title = if self.jobs.is_empty() {
+            " Jobs: no jobs running ".to_string()
+        } else {
+            format!(" Jobs: {} queued ", self.jobs.len())
+        };
+        let table = Table::new(
+            rows,
+            [Constraint::Length(8), Constraint::Min(0), Constraint::Length(16), Constraint::Length(10)],
+        )
+        .header(
+            Row::new(["Id", "Unit", "Type", "State"])
+                .style(Style::default().fg(theme().muted).add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(1))
+                .title(title)
+                .title_alignment(Alignment::Center),
+        )
+        .row_highlight_style(theme().selection())
+        .highlight_symbol(">> ");
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+
+        if self.confirm_clear {
+            self.render_confirm(frame, area);
+        }
+    }
+
+    fn render_confirm(&self, frame: &mut Frame, area: Rect) {
+        let lines = vec![
+            Line::from(vec![
+                Span::raw("Cancel all "),
+                Span::styled(self.jobs.len().to_string(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(" queued jobs?"),
+            ]),
+            Line::raw(""),
+            Line::styled(self.keymap.confirmation_hint(), Style::default().fg(theme().muted)),
+        ];
+
+        let popup_area = centered_area(area, 48, 5);
+        frame.render_widget(Clear, popup_area);
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme().error))
+                .padding(Padding::horizontal(1))
+                .title(" Clear jobs "),
+        );
+        frame.render_widget(popup, popup_area);
+    }
+
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        shortcut_lines(&[
+            ("Navigate", keymap.move_keys()),
+            ("Cancel job", keymap.view_keys(ViewAction::CancelJob)),
+            ("Clear all jobs", keymap.view_key(ViewAction::ClearJobs)),
+            ("Refresh", keymap.key(KeyAction::Refresh)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
+    }
+}
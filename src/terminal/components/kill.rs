@@ -0,0 +1,203 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::unit_signal::{parse_signal, KillTarget, SIGNALS};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::input::TextInput;
+use crate::terminal::components::list::ServiceAction;
+use crate::terminal::components::popup::centered_area;
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+
+pub enum KillSubject {
+    Unit(Service),
+    Process(u32),
+}
+
+pub struct KillDialog {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    subject: Option<KillSubject>,
+    /// Index in `SIGNALS`, one past the end selects the custom signal.
+    selected: usize,
+    custom: TextInput,
+    target: KillTarget,
+    message: Option<String>,
+}
+
+impl KillDialog {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            subject: None,
+            selected: 0,
+            custom: TextInput::default(),
+            target: KillTarget::Main,
+            message: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.subject.is_some()
+    }
+
+    pub fn open(&mut self, subject: KillSubject) {
+        self.subject = Some(subject);
+        self.selected = 0;
+        self.custom = TextInput::default();
+        self.target = KillTarget::Main;
+        self.message = None;
+    }
+
+    fn close(&mut self) {
+        self.subject = None;
+    }
+
+    fn is_custom(&self) -> bool {
+        self.selected == SIGNALS.len()
+    }
+
+    fn signal(&self) -> Option<i32> {
+        if self.is_custom() {
+            parse_signal(self.custom.value())
+        } else {
+            Some(SIGNALS[self.selected].1)
+        }
+    }
+
+    fn targets_unit(&self) -> bool {
+        matches!(self.subject, Some(KillSubject::Unit(_)))
+    }
+
+    fn cycle_target(&mut self, forward: bool) {
+        let len = KillTarget::ALL.len();
+        let index = KillTarget::ALL.iter().position(|target| *target == self.target).unwrap_or(0);
+        let next = if forward { (index + 1) % len } else { (index + len - 1) % len };
+        self.target = KillTarget::ALL[next];
+    }
+
+    fn send(&mut self) {
+        let Some(signal) = self.signal() else {
+            self.message = Some("Enter a signal number (1-64) or name".to_string());
+            return;
+        };
+        let Some(subject) = self.subject.take() else {
+            return;
+        };
+
+        let result = match &subject {
+            KillSubject::Unit(service) => self.usecase.borrow().kill_service(service, self.target, signal).map(|_| {
+                Actions::ServiceAction(ServiceAction::RefreshAll)
+            }),
+            KillSubject::Process(pid) => self.usecase.borrow().kill_process(*pid, signal).map(|()| {
+                Actions::RefreshProcesses
+            }),
+        };
+        match result {
+            Ok(action) => {
+                self.sender.send(AppEvent::Action(action)).unwrap();
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        self.message = None;
+        match key.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Enter => self.send(),
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(SIGNALS.len()),
+            KeyCode::Tab if self.targets_unit() => self.cycle_target(true),
+            KeyCode::BackTab if self.targets_unit() => self.cycle_target(false),
+            KeyCode::Left | KeyCode::Right if !self.is_custom() && self.targets_unit() => self.cycle_target(key.code == KeyCode::Right),
+            _ if self.is_custom() => {
+                self.custom.on_key_event(key);
+            }
+            KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('j') => self.selected = (self.selected + 1).min(SIGNALS.len()),
+            _ => {}
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let title = match &self.subject {
+            Some(KillSubject::Unit(service)) => format!(" Send signal to {} ", service.name()),
+            Some(KillSubject::Process(pid)) => format!(" Send signal to PID {pid} "),
+            None => return,
+        };
+
+        let popup_area = centered_area(area, 50, 16);
+        frame.render_widget(Clear, popup_area);
+
+        let heading = Style::default().fg(theme().heading).add_modifier(Modifier::BOLD);
+        let selected = Style::default().fg(theme().accent).add_modifier(Modifier::BOLD);
+
+        let mut lines = vec![Line::styled("Signal:", heading)];
+        for (index, (name, number)) in SIGNALS.iter().enumerate() {
+            let (marker, style) = if index == self.selected { (">> ", selected) } else { ("   ", Style::default()) };
+            lines.push(Line::styled(format!("{marker}{name} ({number})"), style));
+        }
+        let (marker, style) = if self.is_custom() { (">> ", selected) } else { ("   ", Style::default()) };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker}Custom: "), style),
+            Span::raw(self.custom.value().to_string()),
+        ]));
+        lines.push(Line::raw(""));
+
+        if self.targets_unit() {
+            let mut target = vec![Span::styled("Target: ", heading)];
+            for kill_target in KillTarget::ALL {
+                let style = if kill_target == self.target {
+                    Style::default().fg(theme().on_accent).bg(theme().accent)
+                } else {
+                    Style::default().fg(theme().muted)
+                };
+                target.push(Span::styled(format!(" {} ", kill_target.as_str()), style));
+                target.push(Span::raw(" "));
+            }
+            lines.push(Line::from(target));
+            lines.push(Line::raw(""));
+        }
+
+        if let Some(message) = &self.message {
+            lines.push(Line::styled(message.clone(), Style::default().fg(theme().error)));
+        } else {
+            lines.push(Line::styled(
+                "↑/↓: signal | Tab: target | Enter: send | Esc: cancel",
+                Style::default().fg(theme().muted),
+            ));
+        }
+
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme().accent))
+                .padding(Padding::horizontal(1))
+                .title(title),
+        );
+        frame.render_widget(popup, popup_area);
+
+        if self.is_custom() {
+            #[allow(clippy::cast_possible_truncation)]
+            frame.set_cursor_position(Position::new(
+                popup_area.x + 2 + 11 + self.custom.cursor() as u16,
+                popup_area.y + 2 + SIGNALS.len() as u16,
+            ));
+        }
+    }
+}
@@ -1,26 +1,34 @@
-use crate::usecases::services_manager::ServicesManager;
-use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::layout::Rect;
+use crate::usecases::services_manager::{RepositoryHandle, ServicesManager};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::{
     layout::Constraint,
-    widgets::{Block, Borders, Cell, Row, Table, TableState, Padding},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Padding},
     Frame,
 };
+use serde::Deserialize;
 use std::error::Error;
 use std::sync::mpsc::{Receiver, Sender};
 use std::sync::mpsc;
 use std::rc::Rc;
 use std::cell::RefCell;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::{Duration, Instant};
 use std::collections::HashMap;
 
 use crate::domain::service::Service;
+use crate::domain::unit_job::{JobMode, JobOperation};
+use crate::domain::unit_security::{evaluate, SecurityRating};
+use crate::domain::unit_status::elapsed_since;
 use crate::terminal::app::{Actions, AppEvent};
-use crate::Config;
+use crate::terminal::components::popup::centered_area;
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::config::Config;
+use crate::terminal::theme::theme;
 
 use rayon::prelude::*;
 
@@ -28,6 +36,15 @@ const PADDING: Padding = Padding::new(1, 1, 1, 1);
 
 pub const LOADING_PLACEHOLDER: &str = "Loading";
 
+pub fn security_rating_color(rating: SecurityRating) -> Color {
+    match rating {
+        SecurityRating::Perfect | SecurityRating::Safe | SecurityRating::Ok => theme().success,
+        SecurityRating::Medium => theme().muted,
+        SecurityRating::Exposed => theme().warning,
+        SecurityRating::Unsafe | SecurityRating::Dangerous => theme().error,
+    }
+}
+
 fn resolve_file<'a>(service: &'a Service, states: Option<&'a HashMap<String, String>>) -> &'a str {
     if service.state().file() != LOADING_PLACEHOLDER {
         return service.state().file()
@@ -38,26 +55,70 @@ fn resolve_file<'a>(service: &'a Service, states: Option<&'a HashMap<String, Str
         .unwrap_or_else(|| service.state().file())
 }
 
+/// Optional columns of the unit list; the name is always shown first.
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    Active,
+    State,
+    Load,
+    /// Shown from startup instead of waiting for S.
+    Exposure,
+    Description,
+}
+
+impl Column {
+    pub const DEFAULT: [Column; 4] = [Column::Active, Column::State, Column::Load, Column::Description];
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Name,
+    /// Failed units first, then transitioning, active and inactive ones.
+    Active,
+    Load,
+}
+
+impl SortOrder {
+    /// Sorts units already ordered by name, which stays the order within a group.
+    fn sort(self, services: &mut [Service]) {
+        match self {
+            SortOrder::Name => {}
+            SortOrder::Active => services.sort_by_key(|service| match service.state().active() {
+                "failed" => 0,
+                "activating" | "deactivating" | "reloading" => 1,
+                "active" => 2,
+                _ => 3,
+            }),
+            SortOrder::Load => services.sort_by(|a, b| a.state().load().cmp(b.state().load())),
+        }
+    }
+}
+
 fn build_service_row(
     service: &Service,
     states: Option<&HashMap<String, String>>,
     runtime_label: Option<(&str, &str)>,
+    exposures: Option<&HashMap<String, (f64, SecurityRating)>>,
+    columns: &[Column],
 ) -> Row<'static> {
     let file = resolve_file(service, states);
 
     let highlight_style = Style::default()
-        .fg(Color::Cyan)
+        .fg(theme().accent)
         .add_modifier(Modifier::BOLD);
-    let normal_style = Style::default().fg(Color::Gray);
+    let normal_style = Style::default().fg(theme().muted);
 
     let active_cell = if let Some((service_name, label)) = runtime_label && service_name == service.name() {
-        Cell::from(label.to_string()).style(Style::default().fg(Color::Green))
+        Cell::from(label.to_string()).style(Style::default().fg(theme().success))
     } else {
         let state_style = match service.state().active() {
-            "active" => Style::default().fg(Color::Green),
-            "activating" => Style::default().fg(Color::Yellow),
-            "inactive" => Style::default().fg(Color::DarkGray),
-            _ => Style::default().fg(Color::Red),
+            "active" => Style::default().fg(theme().success),
+            "activating" => Style::default().fg(theme().warning),
+            "inactive" => Style::default().fg(theme().dim),
+            _ => Style::default().fg(theme().error),
         };
         let sub = service.state().sub();
         let active = if sub.is_empty() {
@@ -70,7 +131,7 @@ fn build_service_row(
 
     let file_style = if file == LOADING_PLACEHOLDER {
         Style::default()
-            .fg(Color::Gray)
+            .fg(theme().muted)
             .add_modifier(Modifier::ITALIC | Modifier::DIM)
     }else {
         normal_style
@@ -78,37 +139,66 @@ fn build_service_row(
 
 
 
-    Row::new(vec![
-        Cell::from(service.name().to_string()).style(highlight_style),
-        active_cell,
-        Cell::from(file.to_string()).style(file_style),
-        Cell::from(service.state().load().to_string()).style(normal_style),
-        Cell::from(service.description().to_string()).style(normal_style),
-    ])
+    let mut cells = vec![Cell::from(service.name().to_string()).style(highlight_style)];
+    if columns.contains(&Column::Active) {
+        cells.push(active_cell);
+    }
+    if columns.contains(&Column::State) {
+        cells.push(Cell::from(file.to_string()).style(file_style));
+    }
+    if columns.contains(&Column::Load) {
+        cells.push(Cell::from(service.state().load().to_string()).style(normal_style));
+    }
+    if let Some(exposures) = exposures {
+        cells.push(match exposures.get(service.name()) {
+            Some((exposure, rating)) => Cell::from(format!("{exposure:.1} {}", rating.as_str()))
+                .style(Style::default().fg(security_rating_color(*rating))),
+            None => Cell::from("-").style(normal_style),
+        });
+    }
+    if columns.contains(&Column::Description) {
+        cells.push(Cell::from(service.description().to_string()).style(normal_style));
+    }
+
+    Row::new(cells)
 }
 
-fn generate_rows(services: &[Service], states: Option<&HashMap<String, String>>, service_uptime: Option<(&str, &str)>) -> Vec<Row<'static>> {
+fn generate_rows(
+    services: &[Service],
+    states: Option<&HashMap<String, String>>,
+    service_uptime: Option<(&str, &str)>,
+    exposures: Option<&HashMap<String, (f64, SecurityRating)>>,
+    columns: &[Column],
+) -> Vec<Row<'static>> {
     services
         .par_iter()
-        .map(|service| build_service_row(service, states, service_uptime))
+        .map(|service| build_service_row(service, states, service_uptime, exposures, columns))
         .collect()
 }
 
-fn generate_table<'a>(rows: &'a [Row<'a>], ignore_key_events: bool) -> Table<'a> {
-    let mut table = Table::new(
-        rows.to_owned(),
-        [
-            Constraint::Percentage(20),
-            Constraint::Length(20),
-            Constraint::Length(10),
-            Constraint::Length(10),
-            Constraint::Min(0),
-        ],
-    )
+fn generate_table<'a>(rows: &'a [Row<'a>], ignore_key_events: bool, show_security: bool, columns: &[Column]) -> Table<'a> {
+    let mut widths = vec![Constraint::Percentage(20)];
+    let mut header = vec!["Name"];
+    for (column, title, width) in [(Column::Active, "Active", 20), (Column::State, "State", 10), (Column::Load, "Load", 10)] {
+        if columns.contains(&column) {
+            widths.push(Constraint::Length(width));
+            header.push(title);
+        }
+    }
+    if show_security {
+        widths.push(Constraint::Length(14));
+        header.push("Exposure");
+    }
+    if columns.contains(&Column::Description) {
+        widths.push(Constraint::Min(0));
+        header.push("Description");
+    }
+
+    let mut table = Table::new(rows.to_owned(), widths)
     .header(
-        Row::new(["Name", "Active", "State", "Load", "Description"]).style(
+        Row::new(header).style(
             Style::default()
-                .fg(Color::Gray)
+                .fg(theme().muted)
                 .add_modifier(Modifier::BOLD),
         ),
     )
@@ -117,28 +207,20 @@ fn generate_table<'a>(rows: &'a [Row<'a>], ignore_key_events: bool) -> Table<'a>
             .borders(Borders::NONE)
             .padding(PADDING),
     )
-    .row_highlight_style(
-        Style::default()
-            .bg(Color::Blue)
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
-    )
+    .row_highlight_style(theme().selection())
     .highlight_symbol(">> ");
 
     if ignore_key_events {
-        table = table.row_highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        );
+        table = table.row_highlight_style(theme().inactive_selection());
     }
 
     table
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Default, Debug, clap::ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ActiveFilterState {
+    #[default]
     All,
     Active,
     Inactive,
@@ -165,21 +247,83 @@ impl ActiveFilterState {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ServiceAction {
     Start,
     Stop,
     Restart,
+    Reload,
+    ReloadOrRestart,
+    TryRestart,
+    ReloadOrTryRestart,
     Enable,
     Disable,
     RefreshAll,
     ToggleFilter,
     ToggleMask,
+    ResetFailed,
+    ResetAllFailed,
+}
+
+impl ServiceAction {
+    /// Verb shown when asking for a confirmation.
+    fn label(self) -> &'static str {
+        match self {
+            ServiceAction::Start => "Start",
+            ServiceAction::Stop => "Stop",
+            ServiceAction::Restart => "Restart",
+            ServiceAction::Reload => "Reload",
+            ServiceAction::ReloadOrRestart => "Reload or restart",
+            ServiceAction::TryRestart => "Try to restart",
+            ServiceAction::ReloadOrTryRestart => "Reload or try to restart",
+            ServiceAction::Enable => "Enable",
+            ServiceAction::Disable => "Disable",
+            ServiceAction::RefreshAll => "Refresh",
+            ServiceAction::ToggleFilter => "Toggle the unit filter for",
+            ServiceAction::ToggleMask => "Mask or unmask",
+            ServiceAction::ResetFailed => "Reset the failed state of",
+            ServiceAction::ResetAllFailed => "Reset",
+        }
+    }
+}
+
+/// Uptime and `CanReload` lookup for the selected unit, on the bus of the tab that asked.
+struct TimestampRequest {
+    repository: RepositoryHandle,
+    name: String,
+    active: bool,
 }
 
 pub enum QueryUnitFile {
     Finished(HashMap<String, String>)
 }
  
+/// Units, filters and selection of a tab, kept while another tab is shown.
+#[derive(Default)]
+pub struct ListCache {
+    services: Vec<Service>,
+    /// Shared with the unit-file query of the tab, which may still be running.
+    states: Arc<Mutex<HashMap<String, String>>>,
+    filter_text: String,
+    filter_all: bool,
+    active_filter_state: ActiveFilterState,
+    selected: Option<String>,
+    /// False until the tab is left once, so a new tab starts with the configured filters.
+    shown: bool,
+}
+
+impl ListCache {
+    /// Stores the unit a job settled on; a tab that was never listed stays empty so it is fetched.
+    pub fn update_service(&mut self, service: Service) {
+        match self.services.iter().position(|s| s.name() == service.name()) {
+            Some(pos) => self.services[pos] = service,
+            None if !self.services.is_empty() => self.services.push(service),
+            None => {}
+        }
+    }
+}
+
 pub struct TableServices {
     pub table_state: TableState,
     pub services: Vec<Service>,
@@ -191,19 +335,31 @@ pub struct TableServices {
     usecase: Rc<RefCell<ServicesManager>>,
     filter_all: bool,
     active_filter_state: ActiveFilterState,
-    event_rx: Arc<Mutex<Receiver<QueryUnitFile>>>,
-    event_tx: Arc<Sender<QueryUnitFile>>,
     active_enter_timestamp: Option<u64>,
     selected_service_name: Option<String>,
     last_timestamp_fetch: Option<Instant>,
-    timestamp_request_tx: Sender<String>,
-    timestamp_request_rx: Option<Receiver<String>>,
+    timestamp_request_tx: Sender<TimestampRequest>,
+    timestamp_request_rx: Option<Receiver<TimestampRequest>>,
+    selected_can_reload: Option<bool>,
+    show_security: bool,
+    exposures: Arc<Mutex<HashMap<String, (f64, SecurityRating)>>>,
+    /// Cleared to stop the running security scan; each scan gets its own flag.
+    security_scan: Arc<AtomicBool>,
+    /// Mode of the next job; it falls back to replace once that job is queued.
+    job_mode: JobMode,
+    columns: Vec<Column>,
+    sort: SortOrder,
+    /// `filter_all` and `active_filter_state` of tabs opened for the first time.
+    default_filters: (bool, ActiveFilterState),
+    /// Actions that ask for a confirmation, from the config file.
+    confirm: Vec<ServiceAction>,
+    pending_confirmation: Option<ServiceAction>,
+    keymap: Rc<Keymap>,
 }
 
 impl TableServices {
     pub fn new(sender: Sender<AppEvent>,  usecase: Rc<RefCell<ServicesManager>>) -> Self {
-        let (event_tx, event_rx) = mpsc::channel::<QueryUnitFile>();
-        let (timestamp_request_tx, timestamp_request_rx) = mpsc::channel::<String>();
+        let (timestamp_request_tx, timestamp_request_rx) = mpsc::channel::<TimestampRequest>();
         let filter_all = false;
 
         let mut table_state = TableState::default();
@@ -220,42 +376,57 @@ impl TableServices {
             usecase,
             filter_all,
             active_filter_state: ActiveFilterState::All,
-            event_rx: Arc::new(Mutex::new(event_rx)),
-            event_tx: Arc::new(event_tx),
             active_enter_timestamp: None,
             selected_service_name: None,
             last_timestamp_fetch: None,
             timestamp_request_tx,
             timestamp_request_rx: Some(timestamp_request_rx),
+            selected_can_reload: None,
+            show_security: false,
+            exposures: Arc::new(Mutex::new(HashMap::new())),
+            security_scan: Arc::new(AtomicBool::new(false)),
+            job_mode: JobMode::default(),
+            columns: Column::DEFAULT.to_vec(),
+            sort: SortOrder::default(),
+            default_filters: (false, ActiveFilterState::All),
+            confirm: Vec::new(),
+            pending_confirmation: None,
+            keymap: Rc::new(Keymap::default()),
         }
     }
 
     pub fn init(&mut self, config: &Config) {
-        self.services = self.usecase.borrow().list_services(self.filter_all, self.event_tx.clone())
-            .unwrap_or_default();
-        self.spawn_query_listener();
+        self.filter_all = config.all;
+        self.active_filter_state = config.state;
+        self.default_filters = (config.all, config.state);
+        self.columns.clone_from(&config.columns);
+        self.show_security = config.columns.contains(&Column::Exposure);
+        self.sort = config.sort;
+        self.confirm.clone_from(&config.confirm);
+        self.keymap = config.keymap.clone();
+        self.fetch_services();
         self.spawn_timestamp_worker();
         self.refresh(&config.filter);
     }
 
-    fn spawn_query_listener(&self) {
-        let event_rx = self.event_rx.clone();
+    /// Channel for the unit-file states of one fetch. The listener writes into the states of the
+    /// tab that fetched, so a query finishing after a tab switch does not leak into the new tab.
+    fn spawn_query_listener(&self) -> Arc<Sender<QueryUnitFile>> {
+        let (event_tx, event_rx) = mpsc::channel::<QueryUnitFile>();
         let sender = self.sender.clone();
         let states = self.states.clone();
 
         thread::spawn(move || {
-            loop {
-                let rx = event_rx.lock().unwrap();
-                if let Ok(msg) = rx.recv() {
-                    match msg {
-                        QueryUnitFile::Finished(s) => {
-                            *states.lock().unwrap() = s;
-                            sender.send(AppEvent::Action(Actions::Redraw)).expect("Error");
-                        }
+            while let Ok(msg) = event_rx.recv() {
+                match msg {
+                    QueryUnitFile::Finished(s) => {
+                        *states.lock().unwrap() = s;
+                        sender.send(AppEvent::Action(Actions::Redraw)).expect("Error");
                     }
                 }
             }
         });
+        Arc::new(event_tx)
     }
 
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
@@ -270,13 +441,69 @@ impl TableServices {
                 service.map(|service| (service.name(), label))
             });
 
+        let exposures = if self.show_security { self.exposures.try_lock().ok() } else { None };
+        let exposures = exposures.as_deref();
         let rows = self.states.try_lock()
             .ok()
-            .map(|states| generate_rows(&self.filtered_services, Some(&states), service_uptime))
-            .unwrap_or_else(|| generate_rows(&self.filtered_services, None, service_uptime));
+            .map(|states| generate_rows(&self.filtered_services, Some(&states), service_uptime, exposures, &self.columns))
+            .unwrap_or_else(|| generate_rows(&self.filtered_services, None, service_uptime, exposures, &self.columns));
 
-        let table = generate_table(&rows, self.ignore_key_events);
+        let table = generate_table(&rows, self.ignore_key_events, self.show_security, &self.columns);
         frame.render_stateful_widget(&table, area, &mut self.table_state);
+
+        if let Some(action) = self.pending_confirmation {
+            self.render_confirmation(frame, area, action);
+        }
+    }
+
+    fn render_confirmation(&self, frame: &mut Frame, area: Rect, action: ServiceAction) {
+        let target = match action {
+            ServiceAction::ResetAllFailed => "all failed units".to_string(),
+            _ => self.get_selected_service().map(|service| service.name().to_string()).unwrap_or_default(),
+        };
+        let lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{} ", action.label())),
+                Span::styled(target, Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("?"),
+            ]),
+            Line::raw(""),
+            Line::styled(self.keymap.confirmation_hint(), Style::default().fg(theme().muted)),
+        ];
+
+        let popup_area = centered_area(area, 56, 5);
+        frame.render_widget(Clear, popup_area);
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme().error))
+                .padding(Padding::horizontal(1))
+                .title(" Confirm "),
+        );
+        frame.render_widget(popup, popup_area);
+    }
+
+    /// Runs an action, asking first when the config file lists it under `confirm`.
+    pub fn request_action(&mut self, action: ServiceAction) {
+        if self.confirm.contains(&action) {
+            self.pending_confirmation = Some(action);
+        } else {
+            self.act_on_selected_service(&action);
+        }
+    }
+
+    pub fn is_confirming(&self) -> bool {
+        self.pending_confirmation.is_some()
+    }
+
+    /// Fetches the units again, keeping the selected one, for the periodic refresh.
+    pub fn reload(&mut self) {
+        let selected = self.get_selected_service();
+        self.fetch_and_refresh(&self.old_filter_text.clone());
+        if let Some(service) = selected {
+            self.select_service(service.name());
+        }
     }
 
     pub fn has_active_runtime(&self) -> bool {
@@ -291,21 +518,75 @@ impl TableServices {
 
     fn spawn_timestamp_worker(&mut self) {
         let rx = self.timestamp_request_rx.take().expect("timestamp receiver already taken");
-        let repo = self.usecase.borrow().repository_handle();
         let sender = self.sender.clone();
 
         thread::spawn(move || {
-            while let Ok(n) = rx.recv() {
-                let mut name = n;
+            while let Ok(request) = rx.recv() {
+                let mut request = request;
                 // Drain stale requests, keep only the latest
-                while let Ok(n) = rx.try_recv() {
-                    name = n;
+                while let Ok(r) = rx.try_recv() {
+                    request = r;
+                }
+                let TimestampRequest { repository, name, active } = request;
+                let repo = repository.lock().unwrap();
+                if active {
+                    let ts = repo
+                        .get_active_enter_timestamp(&name)
+                        .ok()
+                        .filter(|&t| t > 0);
+                    let _ = sender.send(AppEvent::Action(Actions::UpdateTimestamp(name.clone(), ts)));
+                }
+                if let Ok(can_reload) = repo.can_reload(&name) {
+                    let _ = sender.send(AppEvent::Action(Actions::UpdateCanReload(name, can_reload)));
+                }
+            }
+        });
+    }
+
+    fn toggle_security(&mut self) {
+        self.show_security = !self.show_security;
+        self.restart_security_scan();
+    }
+
+    /// Stops the running scan and drops its scores, then scores the listed services again when
+    /// the exposure column is shown.
+    fn restart_security_scan(&mut self) {
+        self.security_scan.store(false, Ordering::Relaxed);
+        self.security_scan = Arc::new(AtomicBool::new(true));
+        self.exposures = Arc::new(Mutex::new(HashMap::new()));
+        if self.show_security {
+            self.spawn_security_scan();
+        }
+    }
+
+    /// Scores loaded services in the background, filling the exposure column as results come in.
+    fn spawn_security_scan(&self) {
+        let names: Vec<String> = self
+            .services
+            .iter()
+            .filter(|service| service.name().ends_with(".service") && service.state().load() == "loaded")
+            .map(|service| service.name().to_string())
+            .collect();
+        let repo = self.usecase.borrow().repository_handle();
+        let exposures = self.exposures.clone();
+        let running = self.security_scan.clone();
+        let sender = self.sender.clone();
+
+        thread::spawn(move || {
+            for chunk in names.chunks(20) {
+                for name in chunk {
+                    if !running.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let settings = repo.lock().unwrap().security_settings(name);
+                    if let Ok(settings) = settings {
+                        let report = evaluate(&settings);
+                        exposures.lock().unwrap().insert(name.clone(), (report.exposure(), report.rating()));
+                    }
+                }
+                if sender.send(AppEvent::Action(Actions::Redraw)).is_err() {
+                    return;
                 }
-                let ts = repo.lock().unwrap()
-                    .get_active_enter_timestamp(&name)
-                    .ok()
-                    .filter(|&t| t > 0);
-                let _ = sender.send(AppEvent::Action(Actions::UpdateTimestamp(name, ts)));
             }
         });
     }
@@ -325,13 +606,19 @@ impl TableServices {
 
         if selection_changed {
             self.active_enter_timestamp = None;
+            self.selected_can_reload = None;
         }
 
         self.selected_service_name = current_name;
         self.last_timestamp_fetch = Some(Instant::now());
 
-        if let Some(s) = selected.as_ref().filter(|s| s.state().active() == "active") {
-            let _ = self.timestamp_request_tx.send(s.name().to_string());
+        if let Some(s) = selected.as_ref() {
+            let active = s.state().active() == "active";
+            let _ = self.timestamp_request_tx.send(TimestampRequest {
+                repository: self.usecase.borrow().repository_handle(),
+                name: s.name().to_string(),
+                active,
+            });
         }
     }
 
@@ -342,38 +629,61 @@ impl TableServices {
         }
     }
 
+    pub fn update_can_reload(&mut self, name: &str, can_reload: bool) {
+        if self.selected_service_name.as_deref() == Some(name) {
+            self.selected_can_reload = Some(can_reload);
+        }
+    }
+
     fn format_runtime(&self) -> Option<String> {
         let ts = self.active_enter_timestamp?;
-        let now_micros = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_micros() as u64;
-        if now_micros <= ts {
-            return Some("0s".to_string());
-        }
-        let secs = (now_micros - ts) / 1_000_000;
-        let days = secs / 86400;
-        let hours = (secs % 86400) / 3600;
-        let mins = (secs % 3600) / 60;
-        let s = secs % 60;
-        let prefix = "Uptime:";
-        Some(if days > 0 {
-            format!("{prefix} {days}d {hours}h")
-        } else if hours > 0 {
-            format!("{prefix} {hours}h {mins}m")
-        } else if mins > 0 {
-            format!("{prefix} {mins}m {s}s")
-        } else {
-            format!("{prefix} {s}s")
-        })
+        Some(format!("Uptime: {}", elapsed_since(ts)))
     }
 
-    pub fn set_usecase(&mut self, usecase: Rc<RefCell<ServicesManager>>) {
-        self.usecase = usecase;
-        self.table_state.select(Some(0));
-        self.services.clear();
+    /// Hands over the units, filters and selection of the tab being left.
+    pub fn take_cache(&mut self) -> ListCache {
+        let selected = self.get_selected_service().map(|service| service.name().to_string());
         self.filtered_services.clear();
-        self.fetch_and_refresh(&self.old_filter_text.clone());
+        self.security_scan.store(false, Ordering::Relaxed);
+        ListCache {
+            services: std::mem::take(&mut self.services),
+            states: std::mem::take(&mut self.states),
+            filter_text: std::mem::take(&mut self.old_filter_text),
+            filter_all: self.filter_all,
+            active_filter_state: self.active_filter_state,
+            selected,
+            shown: true,
+        }
+    }
+
+    /// Shows the tab being opened as it was left, fetching its units if it was never shown.
+    pub fn restore_cache(&mut self, cache: ListCache) {
+        self.states = cache.states;
+        (self.filter_all, self.active_filter_state) = if cache.shown {
+            (cache.filter_all, cache.active_filter_state)
+        } else {
+            self.default_filters
+        };
+        self.table_state.select(Some(0));
+        if cache.services.is_empty() {
+            self.fetch_and_refresh(&cache.filter_text);
+        } else {
+            self.services = cache.services;
+            // Exposures belong to the bus that was scanned, score this tab's units again.
+            self.restart_security_scan();
+            self.refresh(&cache.filter_text);
+        }
+
+        if let Some(index) = cache
+            .selected
+            .and_then(|name| self.filtered_services.iter().position(|service| service.name() == name))
+        {
+            self.table_state.select(Some(index));
+        }
+    }
+
+    pub fn filter_text(&self) -> &str {
+        &self.old_filter_text
     }
 
     pub fn set_ignore_key_events(&mut self, has_ignore_key_events: bool) {
@@ -385,6 +695,17 @@ impl TableServices {
             .and_then(|idx| self.filtered_services.get(idx).cloned())
     }
 
+    /// Selects a unit by name, returning false when the current filters hide it.
+    pub fn select_service(&mut self, name: &str) -> bool {
+        match self.filtered_services.iter().position(|service| service.name() == name) {
+            Some(index) => {
+                self.table_state.select(Some(index));
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn set_selected_index(&mut self, index: usize) {
         self.table_state.select(Some(index));
     }
@@ -411,8 +732,10 @@ impl TableServices {
     }
 
     fn fetch_services(&mut self) {
-        self.services = self.usecase.borrow().list_services(self.filter_all, self.event_tx.clone())
+        self.services = self.usecase.borrow().list_services(self.filter_all, self.spawn_query_listener())
             .unwrap_or_default();
+        self.sort.sort(&mut self.services);
+        self.restart_security_scan();
     }
 
     fn fetch_and_refresh(&mut self, filter_text: &str) {
@@ -443,42 +766,59 @@ impl TableServices {
     }
 
     pub fn on_key_event(&mut self, key: KeyEvent) {
+        if let Some(action) = self.pending_confirmation.take() {
+            if self.keymap.is_view(&key, ViewAction::Confirm) {
+                self.act_on_selected_service(&action);
+            } else if self.keymap.is_view(&key, ViewAction::Cancel) {
+                self.set_ignore_key_events(false);
+            } else {
+                self.pending_confirmation = Some(action);
+            }
+            return;
+        }
+
         if self.ignore_key_events {
             return;
         }
 
-        self.set_ignore_key_events(true);
+        let Some(action) = self.keymap.action(&key) else {
+            return;
+        };
+
+        // Reload is disabled for units that do not support it (CanReload=no)
+        if action == KeyAction::Reload && self.selected_can_reload == Some(false) {
+            return;
+        }
 
-        match key.code {
-            KeyCode::Char('r') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::Restart))).unwrap();
-                return;
-            }
-            KeyCode::Char('s') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::Start))).unwrap();
-                return;
-            }
-            KeyCode::Char('x') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::Stop))).unwrap();
-                return;
-            }
-            KeyCode::Char('e') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::Enable))).unwrap();
-                return;
-            }
-            KeyCode::Char('d') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::Disable))).unwrap();
-                return;
-            }
-            KeyCode::Char('u') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::RefreshAll))).unwrap();
-                return;
-            }
-            KeyCode::Char('f') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::ToggleFilter))).unwrap();
-                return;
-            }
-            KeyCode::Char('a') => {
+        let service_action = match action {
+            KeyAction::Restart => Some(ServiceAction::Restart),
+            KeyAction::ResetAllFailed => Some(ServiceAction::ResetAllFailed),
+            KeyAction::ResetFailed => Some(ServiceAction::ResetFailed),
+            KeyAction::Reload => Some(ServiceAction::Reload),
+            KeyAction::ReloadOrRestart => Some(ServiceAction::ReloadOrRestart),
+            KeyAction::TryRestart => Some(ServiceAction::TryRestart),
+            KeyAction::ReloadOrTryRestart => Some(ServiceAction::ReloadOrTryRestart),
+            KeyAction::Start => Some(ServiceAction::Start),
+            KeyAction::Stop => Some(ServiceAction::Stop),
+            KeyAction::Enable => Some(ServiceAction::Enable),
+            KeyAction::Disable => Some(ServiceAction::Disable),
+            KeyAction::Refresh => Some(ServiceAction::RefreshAll),
+            KeyAction::ToggleUnitFilter => Some(ServiceAction::ToggleFilter),
+            KeyAction::ToggleMask => Some(ServiceAction::ToggleMask),
+            _ => None,
+        };
+        if let Some(service_action) = service_action {
+            self.set_ignore_key_events(true);
+            self.sender.send(AppEvent::Action(Actions::ServiceAction(service_action))).unwrap();
+            return;
+        }
+
+        match action {
+            KeyAction::MoveDown => self.select_next(),
+            KeyAction::MoveUp => self.select_previous(),
+            KeyAction::PageDown => self.select_page_down(),
+            KeyAction::PageUp => self.select_page_up(),
+            KeyAction::CycleStateFilter => {
                 self.active_filter_state = self.active_filter_state.next();
                 self.refresh(&self.old_filter_text.clone());
                 // Select the first element only if the list is not empty
@@ -487,38 +827,89 @@ impl TableServices {
                 } else {
                     self.table_state.select(Some(0));
                 }
-                self.set_ignore_key_events(false);
-                return;
-            }
-            KeyCode::Char('m') => {
-                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::ToggleMask))).unwrap();
-                return;
             }
-            KeyCode::Char('?') => {
+            KeyAction::Help => {
+                self.set_ignore_key_events(true);
                 self.sender.send(AppEvent::Action(Actions::ShowHelp)).unwrap();
-                return;
+            }
+            KeyAction::Status => {
+                self.sender.send(AppEvent::Action(Actions::GoStatus)).unwrap();
+            }
+            KeyAction::Details => {
+                self.sender.send(AppEvent::Action(Actions::GoDetails)).unwrap();
+            }
+            KeyAction::Log => {
+                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+            }
+            KeyAction::NewUnit => {
+                self.sender.send(AppEvent::Action(Actions::GoNewUnit)).unwrap();
+            }
+            KeyAction::Overrides => {
+                self.sender.send(AppEvent::Action(Actions::GoDelta)).unwrap();
+            }
+            KeyAction::Kill => {
+                self.sender.send(AppEvent::Action(Actions::OpenKillDialog)).unwrap();
+            }
+            KeyAction::Processes => {
+                self.sender.send(AppEvent::Action(Actions::GoProcesses)).unwrap();
+            }
+            KeyAction::Boot => {
+                self.sender.send(AppEvent::Action(Actions::GoBoot)).unwrap();
+            }
+            KeyAction::Exposure => self.toggle_security(),
+            KeyAction::Targets => {
+                self.sender.send(AppEvent::Action(Actions::GoTargets)).unwrap();
+            }
+            KeyAction::JobMode => self.job_mode = self.job_mode.next(),
+            KeyAction::ResourceLimits => {
+                self.sender.send(AppEvent::Action(Actions::OpenResourcesDialog)).unwrap();
+            }
+            KeyAction::Hosts => {
+                self.sender.send(AppEvent::Action(Actions::OpenHostSelector)).unwrap();
+            }
+            KeyAction::Run => {
+                self.sender.send(AppEvent::Action(Actions::GoRun)).unwrap();
+            }
+            KeyAction::Jobs => {
+                self.sender.send(AppEvent::Action(Actions::GoJobs)).unwrap();
             }
             _ => {}
         }
+    }
 
-        self.set_ignore_key_events(false);
-
-        let up_keys = [KeyCode::Up, KeyCode::Char('k')];
-        let down_keys = [KeyCode::Down, KeyCode::Char('j')];
+    /// Selects the clicked unit or moves the selection with the wheel, without wrapping around.
+    /// Returns true when a unit was clicked.
+    pub fn on_mouse_event(&mut self, mouse: MouseEvent, area: Rect) -> bool {
+        if self.ignore_key_events || self.pending_confirmation.is_some() || self.filtered_services.is_empty() {
+            return false;
+        }
 
-        match key.code {
-            code if down_keys.contains(&code) => self.select_next(),
-            code if up_keys.contains(&code) => self.select_previous(),
-            KeyCode::PageDown => self.select_page_down(),
-            KeyCode::PageUp => self.select_page_up(),
-            KeyCode::Char('c') => {
-                self.sender.send(AppEvent::Action(Actions::GoDetails)).unwrap();
+        let last = self.filtered_services.len() - 1;
+        match mouse.kind {
+            MouseEventKind::ScrollDown => {
+                let next = self.table_state.selected().map_or(0, |index| (index + 1).min(last));
+                self.table_state.select(Some(next));
             }
-            KeyCode::Char('v') => {
-                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+            MouseEventKind::ScrollUp => {
+                let previous = self.table_state.selected().map_or(0, |index| index.saturating_sub(1));
+                self.table_state.select(Some(previous));
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Rows start below the top padding and the header.
+                let first_row = area.y + PADDING.top + 1;
+                let rows_end = area.bottom().saturating_sub(PADDING.bottom);
+                if !area.contains(Position::new(mouse.column, mouse.row)) || mouse.row < first_row || mouse.row >= rows_end {
+                    return false;
+                }
+                let index = self.table_state.offset() + usize::from(mouse.row - first_row);
+                if index <= last {
+                    self.table_state.select(Some(index));
+                    return true;
+                }
             }
             _ => {}
         }
+        false
     }
 
     fn select_page_down(&mut self) {
@@ -580,6 +971,16 @@ impl TableServices {
     }
 
     pub fn act_on_selected_service(&mut self, action: &ServiceAction) {
+        if matches!(action, ServiceAction::ResetAllFailed) {
+            let result = self.usecase.borrow().reset_all_failed();
+            match result {
+                Ok(()) => self.fetch_and_refresh(&self.old_filter_text.clone()),
+                Err(e) => self.sender.send(AppEvent::Error(e.to_string())).unwrap(),
+            }
+            self.set_ignore_key_events(false);
+            return;
+        }
+
         if let Some(service) = self.get_selected_service() {
             let binding_usecase = self.usecase.clone();
             let usecase = binding_usecase.borrow();
@@ -609,9 +1010,15 @@ impl TableServices {
                         self.fetch_and_refresh(&self.old_filter_text.clone());
                     }
                 },
-                ServiceAction::Start => self.handle_service_result(usecase.start_service(&service)),
-                ServiceAction::Stop => self.handle_service_result(usecase.stop_service(&service)),
-                ServiceAction::Restart => self.handle_service_result(usecase.restart_service(&service)),
+                ServiceAction::Start => self.queue_job(&usecase, JobOperation::Start, &service),
+                ServiceAction::Stop => self.queue_job(&usecase, JobOperation::Stop, &service),
+                ServiceAction::Restart => self.queue_job(&usecase, JobOperation::Restart, &service),
+                ServiceAction::Reload => self.queue_job(&usecase, JobOperation::Reload, &service),
+                ServiceAction::ReloadOrRestart => self.queue_job(&usecase, JobOperation::ReloadOrRestart, &service),
+                ServiceAction::TryRestart => self.queue_job(&usecase, JobOperation::TryRestart, &service),
+                ServiceAction::ReloadOrTryRestart => self.queue_job(&usecase, JobOperation::ReloadOrTryRestart, &service),
+                ServiceAction::ResetFailed => self.handle_service_result(usecase.reset_failed_service(&service)),
+                ServiceAction::ResetAllFailed => {},
                 ServiceAction::Enable => self.handle_service_result(usecase.enable_service(&service)),
                 ServiceAction::Disable => self.handle_service_result(usecase.disable_service(&service)),
                 ServiceAction::ToggleFilter => {
@@ -627,6 +1034,21 @@ impl TableServices {
         self.set_ignore_key_events(false);
     }
 
+    /// Runs the job in the background; the outcome comes back as `Actions::JobFinished`, tagged
+    /// with the repository of this tab.
+    fn queue_job(&mut self, usecase: &ServicesManager, operation: JobOperation, service: &Service) {
+        let sender = self.sender.clone();
+        let mode = std::mem::take(&mut self.job_mode);
+        let repository = usecase.repository_handle();
+        usecase.queue_job(operation, mode, service, move |result| {
+            let _ = sender.send(AppEvent::Action(Actions::JobFinished(repository, result)));
+        });
+    }
+
+    pub fn job_finished(&mut self, result: Result<Service, String>) {
+        self.handle_service_result(result.map_err(Into::into));
+    }
+
     fn handle_service_result(&mut self, result: Result<Service, Box<dyn Error>>) {
         match result {
             Ok(service) => {
@@ -649,6 +1071,13 @@ impl TableServices {
         self.filtered_services.is_empty()
     }
 
+    pub fn failed_count(&self) -> usize {
+        self.services
+            .iter()
+            .filter(|service| service.state().active() == "failed")
+            .count()
+    }
+
     pub fn get_active_filter_state(&self) -> ActiveFilterState {
         self.active_filter_state
     }
@@ -659,13 +1088,21 @@ impl TableServices {
             help_text.push(Line::from(Span::styled(
                 "Actions on the selected service",
                 Style::default()
-                    .fg(Color::LightMagenta)
+                    .fg(theme().shortcut_heading)
                     .add_modifier(Modifier::BOLD),
             )));
 
-            help_text.push(Line::from(
-                "Navigate: ↑/↓ | Switch tab: ←/→ | Start: s | Stop: x | Restart: r | Enable: e | Disable: d | List all units: f | Filter: a | Mask/Unmask: m | Refresh: u | Log: v | Unit File: c | Help: ?"
-            ));
+            help_text.push(Line::from(self.keymap.shortcut_line(self.selected_can_reload != Some(false))));
+            if self.job_mode != JobMode::default() {
+                help_text.push(Line::styled(
+                    format!(
+                        "Next job runs in {} mode ({} to change)",
+                        self.job_mode.as_str(),
+                        self.keymap.key(KeyAction::JobMode)
+                    ),
+                    Style::default().fg(theme().heading).add_modifier(Modifier::BOLD),
+                ));
+            }
         }
 
         help_text
@@ -1,8 +1,8 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::{
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, List, ListItem},
     Frame,
@@ -16,9 +16,14 @@ use std::cell::RefCell;
 use textwrap::wrap;
 use rayon::prelude::*;
 
+use crate::config::DEFAULT_LOG_LINES;
 use crate::domain::service::Service;
 use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
 use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::mouse::WHEEL_LINES;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
 
 fn render_loading(frame: &mut Frame, area: Rect) {
     let block = Block::default().borders(Borders::ALL);
@@ -50,15 +55,15 @@ fn render_loading(frame: &mut Frame, area: Rect) {
 }
 
 enum BorderColor {
-    White,
-    Orange,
+    Idle,
+    Following,
 }
 
 impl BorderColor {
     fn to_color(&self) -> Color {
         match self {
-            BorderColor::White => Color::White,
-            BorderColor::Orange => Color::Rgb(255, 165, 0),
+            BorderColor::Idle => theme().text,
+            BorderColor::Following => theme().log_follow,
         }
     }
 }
@@ -71,18 +76,22 @@ pub struct ServiceLog {
     auto_refresh: Arc<Mutex<bool>>,
     usecase: Rc<RefCell<ServicesManager>>,
     log: String,
+    log_lines: usize,
+    keymap: Rc<Keymap>,
 }
 
 impl ServiceLog {
     pub fn new(sender: Sender<AppEvent>,  usecase: Rc<RefCell<ServicesManager>>) -> Self {
         Self {
-            border_color: BorderColor::White,
+            border_color: BorderColor::Idle,
             service_name: String::new(),
             scroll: 0,
             sender,
             auto_refresh: Arc::new(Mutex::new(false)),
             usecase,
             log: String::new(),
+            log_lines: DEFAULT_LOG_LINES,
+            keymap: Rc::new(Keymap::default()),
         }
     }
 
@@ -142,9 +151,9 @@ impl ServiceLog {
 
     fn set_auto_refresh(&mut self, value: bool) {
         self.border_color = if value {
-            BorderColor::Orange
+            BorderColor::Following
         } else {
-            BorderColor::White
+            BorderColor::Idle
         };
 
         if let Ok(mut auto) = self.auto_refresh.lock() {
@@ -153,64 +162,46 @@ impl ServiceLog {
     }
 
     pub fn on_key_event(&mut self, key: KeyEvent) {
-        let right_keys = [KeyCode::Right, KeyCode::Char('l')];
-        let left_keys = [KeyCode::Left, KeyCode::Char('h')];
-        let up_keys = [KeyCode::Up, KeyCode::Char('k')];
-        let down_keys = [KeyCode::Down, KeyCode::Char('j')];
-
-        match key.code {
-            code if right_keys.contains(&code) => {
-                self.reset();
-                self.sender.send(AppEvent::Action(Actions::GoDetails)).unwrap();
-            }
-            code if left_keys.contains(&code) => {
-                self.reset();
-                self.sender.send(AppEvent::Action(Actions::GoDetails)).unwrap();
-            }
-            code if up_keys.contains(&code) => {
-                self.scroll = self.scroll.saturating_add(1);
-            }
-            code if down_keys.contains(&code) => {
-                self.scroll = self.scroll.saturating_sub(1);
-            }
-            KeyCode::PageUp => {
-                self.scroll = self.scroll.saturating_add(10);
-            }
-            KeyCode::PageDown => {
-                self.scroll = self.scroll.saturating_sub(10);
-            }
-            KeyCode::Char('a') => {
-                self.toogle_auto_refresh();
-                self.auto_refresh_thread();
-            }
-            KeyCode::Char('q') | KeyCode::Esc => {
-                self.reset();
-                self.exit();
-            }
+        let keymap = self.keymap.clone();
+        if keymap.is(&key, KeyAction::NextTab) || keymap.is(&key, KeyAction::PreviousTab) {
+            self.reset();
+            self.sender.send(AppEvent::Action(Actions::GoDetails)).unwrap();
+        } else if keymap.is(&key, KeyAction::MoveUp) {
+            self.scroll = self.scroll.saturating_add(1);
+        } else if keymap.is(&key, KeyAction::MoveDown) {
+            self.scroll = self.scroll.saturating_sub(1);
+        } else if keymap.is(&key, KeyAction::PageUp) {
+            self.scroll = self.scroll.saturating_add(10);
+        } else if keymap.is(&key, KeyAction::PageDown) {
+            self.scroll = self.scroll.saturating_sub(10);
+        } else if keymap.is_view(&key, ViewAction::Follow) {
+            self.toogle_auto_refresh();
+            self.auto_refresh_thread();
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.reset();
+            self.exit();
+        }
+    }
+
+    pub fn on_mouse_event(&mut self, mouse: MouseEvent) {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.scroll = self.scroll.saturating_add(WHEEL_LINES),
+            MouseEventKind::ScrollDown => self.scroll = self.scroll.saturating_sub(WHEEL_LINES),
             _ => {}
         }
     }
 
     pub fn shortcuts(&self) -> Vec<Line<'_>> {
         let is_refreshing = self.auto_refresh.lock().map(|r| *r).unwrap_or(false);
-        let mut auto_refresh_label = "Enable auto-refresh";
-        if is_refreshing {
-            auto_refresh_label = "Disable auto-refresh";
-        }
-
-        let help_text = vec![
-            Line::from(vec![Span::styled(
-                "Actions",
-                Style::default()
-                    .fg(Color::LightMagenta)
-                    .add_modifier(Modifier::BOLD),
-            )]),
-            Line::from(format!(
-                "Scroll: ↑/↓ | Switch tabs: ←/→ | {auto_refresh_label}: a | Go back: q/Esc",
-            )),
-        ];
-
-        help_text
+        let auto_refresh_label = if is_refreshing { "Disable auto-refresh" } else { "Enable auto-refresh" };
+
+        let keymap = &self.keymap;
+        shortcut_lines(&[
+            ("Scroll", keymap.move_keys()),
+            ("Switch tabs", keymap.pane_keys()),
+            (auto_refresh_label, keymap.view_key(ViewAction::Follow)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
     }
 
     pub fn reset(&mut self) {
@@ -240,9 +231,17 @@ impl ServiceLog {
         });
     }
 
+    pub fn set_log_lines(&mut self, lines: usize) {
+        self.log_lines = lines;
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
     pub fn fetch_log_and_dispatch(&mut self, service: &Service) {
         let event_tx = self.sender.clone();
-        if let Ok(log) = self.usecase.borrow().get_log(service) {
+        if let Ok(log) = self.usecase.borrow().get_log(service, self.log_lines) {
             event_tx
                 .send(AppEvent::Action(Actions::Updatelog((
                     service.name().to_string(),
@@ -1,4 +1,19 @@
+pub mod boot;
+pub mod delta;
 pub mod details;
+pub mod editor;
 pub mod filter;
+pub mod hosts;
+pub mod input;
+pub mod jobs;
+pub mod kill;
 pub mod list;
 pub mod log;
+pub mod new_unit;
+pub mod popup;
+pub mod processes;
+pub mod resources;
+pub mod run;
+pub mod shortcuts;
+pub mod status;
+pub mod targets;
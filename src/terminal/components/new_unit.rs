@@ -0,0 +1,330 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::unit_template::{UnitKind, UnitTemplate, RESTART_POLICIES, SERVICE_TYPES};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::details::styled_unit_file_lines;
+use crate::terminal::components::input::TextInput;
+use crate::terminal::components::list::ServiceAction;
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+const LABEL_WIDTH: usize = 14;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+    Kind,
+    Name,
+    Description,
+    ExecStart,
+    Trigger,
+    ServiceType,
+    User,
+    Restart,
+    WantedBy,
+}
+
+const FIELDS: [Field; 9] = [
+    Field::Kind,
+    Field::Name,
+    Field::Description,
+    Field::ExecStart,
+    Field::Trigger,
+    Field::ServiceType,
+    Field::User,
+    Field::Restart,
+    Field::WantedBy,
+];
+
+pub struct NewUnitWizard {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    kind: UnitKind,
+    service_type: usize,
+    restart: usize,
+    name: TextInput,
+    description: TextInput,
+    exec_start: TextInput,
+    trigger: TextInput,
+    user: TextInput,
+    wanted_by: TextInput,
+    focus: Field,
+    session: bool,
+    scroll: u16,
+    message: Option<String>,
+}
+
+impl NewUnitWizard {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            kind: UnitKind::Service,
+            service_type: 0,
+            restart: 0,
+            name: TextInput::default(),
+            description: TextInput::default(),
+            exec_start: TextInput::default(),
+            trigger: TextInput::default(),
+            user: TextInput::default(),
+            wanted_by: TextInput::new(UnitKind::Service.default_install_target(false)),
+            focus: Field::Kind,
+            session: false,
+            scroll: 0,
+            message: None,
+        }
+    }
+
+    pub fn open(&mut self, session: bool) {
+        let sender = self.sender.clone();
+        let usecase = self.usecase.clone();
+        *self = Self::new(sender, usecase);
+        self.session = session;
+        self.wanted_by.set_value(self.kind.default_install_target(session));
+    }
+
+    fn template(&self) -> UnitTemplate {
+        UnitTemplate {
+            kind: self.kind,
+            name: self.name.value().to_string(),
+            description: self.description.value().to_string(),
+            exec_start: self.exec_start.value().to_string(),
+            trigger: self.trigger.value().to_string(),
+            service_type: SERVICE_TYPES[self.service_type].to_string(),
+            user: self.user.value().to_string(),
+            restart: RESTART_POLICIES[self.restart].to_string(),
+            wanted_by: self.wanted_by.value().to_string(),
+        }
+    }
+
+    fn visible_fields(&self) -> Vec<Field> {
+        FIELDS
+            .iter()
+            .copied()
+            .filter(|field| *field != Field::Trigger || self.kind.trigger_directive().is_some())
+            .collect()
+    }
+
+    fn label(&self, field: Field) -> &'static str {
+        match field {
+            Field::Kind => "Type",
+            Field::Name => "Name",
+            Field::Description => "Description",
+            Field::ExecStart => "ExecStart",
+            Field::Trigger => self.kind.trigger_directive().unwrap_or(""),
+            Field::ServiceType => "Service type",
+            Field::User => "User",
+            Field::Restart => "Restart",
+            Field::WantedBy => "WantedBy",
+        }
+    }
+
+    fn input(&self, field: Field) -> Option<&TextInput> {
+        match field {
+            Field::Name => Some(&self.name),
+            Field::Description => Some(&self.description),
+            Field::ExecStart => Some(&self.exec_start),
+            Field::Trigger => Some(&self.trigger),
+            Field::User => Some(&self.user),
+            Field::WantedBy => Some(&self.wanted_by),
+            Field::Kind | Field::ServiceType | Field::Restart => None,
+        }
+    }
+
+    fn input_mut(&mut self, field: Field) -> Option<&mut TextInput> {
+        match field {
+            Field::Name => Some(&mut self.name),
+            Field::Description => Some(&mut self.description),
+            Field::ExecStart => Some(&mut self.exec_start),
+            Field::Trigger => Some(&mut self.trigger),
+            Field::User => Some(&mut self.user),
+            Field::WantedBy => Some(&mut self.wanted_by),
+            Field::Kind | Field::ServiceType | Field::Restart => None,
+        }
+    }
+
+    fn choice_value(&self, field: Field) -> &'static str {
+        match field {
+            Field::Kind => self.kind.as_str(),
+            Field::ServiceType => SERVICE_TYPES[self.service_type],
+            Field::Restart => RESTART_POLICIES[self.restart],
+            _ => "",
+        }
+    }
+
+    fn cycle_choice(&mut self, forward: bool) {
+        let step = |index: usize, len: usize| if forward { (index + 1) % len } else { (index + len - 1) % len };
+        match self.focus {
+            Field::Kind => {
+                let index = UnitKind::ALL.iter().position(|kind| *kind == self.kind).unwrap_or(0);
+                let previous_target = self.kind.default_install_target(self.session);
+                self.kind = UnitKind::ALL[step(index, UnitKind::ALL.len())];
+                if self.wanted_by.value() == previous_target {
+                    self.wanted_by.set_value(self.kind.default_install_target(self.session));
+                }
+            }
+            Field::ServiceType => self.service_type = step(self.service_type, SERVICE_TYPES.len()),
+            Field::Restart => self.restart = step(self.restart, RESTART_POLICIES.len()),
+            _ => {}
+        }
+    }
+
+    fn move_focus(&mut self, forward: bool) {
+        let fields = self.visible_fields();
+        let index = fields.iter().position(|field| *field == self.focus).unwrap_or(0);
+        let next = if forward {
+            (index + 1) % fields.len()
+        } else {
+            (index + fields.len() - 1) % fields.len()
+        };
+        self.focus = fields[next];
+    }
+
+    fn preview(&self) -> String {
+        let directory = self.usecase.borrow().unit_file_directory().unwrap_or_default();
+        self.template()
+            .render()
+            .into_iter()
+            .map(|(file_name, content)| format!("# {}\n{content}", directory.join(file_name).display()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn save(&mut self) {
+        let template = self.template();
+        if let Err(message) = template.validate() {
+            self.message = Some(message);
+            return;
+        }
+
+        let result = self.usecase.borrow().create_unit_files(&template.render());
+        match result {
+            Ok(_) => {
+                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::RefreshAll))).unwrap();
+                self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        self.message = None;
+
+        match key.code {
+            KeyCode::Esc => {
+                self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => self.save(),
+            KeyCode::Tab | KeyCode::Down | KeyCode::Enter => self.move_focus(true),
+            KeyCode::BackTab | KeyCode::Up => self.move_focus(false),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') if self.input(self.focus).is_none() => {
+                self.cycle_choice(key.code != KeyCode::Left);
+            }
+            _ => {
+                if let Some(input) = self.input_mut(self.focus) {
+                    input.on_key_event(key);
+                }
+            }
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [form_area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(area);
+
+        let mut lines: Vec<Line<'_>> = Vec::new();
+        let mut cursor = None;
+        for (row, field) in self.visible_fields().into_iter().enumerate() {
+            let focused = field == self.focus;
+            let label_style = if focused {
+                Style::default().fg(theme().heading).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().muted)
+            };
+            let label = Span::styled(format!("{:<LABEL_WIDTH$}", self.label(field)), label_style);
+
+            let value = match self.input(field) {
+                Some(input) => {
+                    if focused {
+                        cursor = Some((row, input.cursor()));
+                    }
+                    Span::raw(input.value().to_string())
+                }
+                None if focused => Span::styled(
+                    format!("< {} >", self.choice_value(field)),
+                    Style::default().fg(theme().accent),
+                ),
+                None => Span::raw(self.choice_value(field)),
+            };
+            lines.push(Line::from(vec![label, value]));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            match self.usecase.borrow().unit_file_directory() {
+                Ok(directory) => format!("Target directory: {}", directory.display()),
+                Err(e) => e.to_string(),
+            },
+            Style::default().fg(theme().dim),
+        ));
+        if let Some(message) = &self.message {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(message.clone(), Style::default().fg(theme().error)));
+        }
+
+        let form = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(1))
+                .title(" New unit ")
+                .title_alignment(Alignment::Center),
+        );
+        frame.render_widget(form, form_area);
+
+        if let Some((row, column)) = cursor {
+            #[allow(clippy::cast_possible_truncation)]
+            frame.set_cursor_position(Position::new(
+                form_area.x + 2 + (LABEL_WIDTH + column) as u16,
+                form_area.y + 1 + row as u16,
+            ));
+        }
+
+        let preview = self.preview();
+        let paragraph = Paragraph::new(styled_unit_file_lines(&preview))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Preview ")
+                    .title_alignment(Alignment::Center),
+            )
+            .scroll((self.scroll, 0))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, preview_area);
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        shortcut_lines(&[
+            ("Next field", "Tab/↓".to_string()),
+            ("Previous field", "Shift+Tab/↑".to_string()),
+            ("Change option", "←/→".to_string()),
+            ("Scroll preview", "PgUp/PgDn".to_string()),
+            ("Write and reload", "Ctrl+s".to_string()),
+            ("Cancel", "Esc".to_string()),
+        ])
+    }
+}
@@ -0,0 +1,236 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Padding, Row, Table, TableState},
+    Frame,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::domain::service::Service;
+use crate::domain::unit_process::{process_tree, UnitProcess};
+use crate::domain::unit_status::format_bytes;
+use crate::infrastructure::procfs::clock_ticks_per_second;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+pub struct ProcessTree {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    service: Option<Service>,
+    processes: Vec<UnitProcess>,
+    /// CPU ticks of each pid at the previous refresh, used to compute the CPU usage in between.
+    previous: HashMap<u32, u64>,
+    sampled_at: Option<Instant>,
+    cpu: HashMap<u32, f64>,
+    table_state: TableState,
+    auto_refresh: Arc<Mutex<bool>>,
+    keymap: Rc<Keymap>,
+}
+
+impl ProcessTree {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            service: None,
+            processes: Vec::new(),
+            previous: HashMap::new(),
+            sampled_at: None,
+            cpu: HashMap::new(),
+            table_state: TableState::default(),
+            auto_refresh: Arc::new(Mutex::new(false)),
+            keymap: Rc::new(Keymap::default()),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
+    pub fn open(&mut self, service: Service) {
+        self.service = Some(service);
+        self.processes.clear();
+        self.previous.clear();
+        self.cpu.clear();
+        self.sampled_at = None;
+        self.table_state.select(None);
+        self.refresh();
+        self.start_auto_refresh();
+    }
+
+    pub fn refresh(&mut self) {
+        let Some(service) = &self.service else {
+            return;
+        };
+        let result = self.usecase.borrow().unit_processes(service);
+        match result {
+            Ok(processes) => self.update(processes),
+            Err(e) => {
+                self.set_auto_refresh(false);
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn update(&mut self, processes: Vec<UnitProcess>) {
+        let now = Instant::now();
+        if let Some(sampled_at) = self.sampled_at {
+            let elapsed = now.duration_since(sampled_at).as_secs_f64();
+            let ticks = clock_ticks_per_second() as f64;
+            self.cpu = processes
+                .iter()
+                .filter_map(|process| {
+                    let before = self.previous.get(&process.pid)?;
+                    let used = process.cpu_ticks.saturating_sub(*before) as f64 / ticks;
+                    (elapsed > 0.0).then(|| (process.pid, used / elapsed * 100.0))
+                })
+                .collect();
+        }
+        self.previous = processes.iter().map(|process| (process.pid, process.cpu_ticks)).collect();
+        self.sampled_at = Some(now);
+
+        let selected_pid = self.selected().map(|process| process.pid);
+        self.processes = processes;
+        let tree = process_tree(&self.processes);
+        let index = selected_pid
+            .and_then(|pid| tree.iter().position(|(_, process)| process.pid == pid))
+            .or_else(|| (!tree.is_empty()).then_some(0));
+        self.table_state.select(index);
+    }
+
+    fn selected(&self) -> Option<&UnitProcess> {
+        let index = self.table_state.selected()?;
+        process_tree(&self.processes).get(index).map(|(_, process)| *process)
+    }
+
+    fn select(&mut self, offset: isize) {
+        let len = self.processes.len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select(Some(selected.saturating_add_signed(offset).min(len - 1)));
+    }
+
+    fn set_auto_refresh(&mut self, value: bool) {
+        if let Ok(mut auto) = self.auto_refresh.lock() {
+            *auto = value;
+        }
+    }
+
+    fn start_auto_refresh(&mut self) {
+        // Replace the flag so a thread left over from a previous visit stops on its own.
+        self.set_auto_refresh(false);
+        self.auto_refresh = Arc::new(Mutex::new(true));
+
+        let auto_refresh = Arc::clone(&self.auto_refresh);
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            loop {
+                thread::sleep(Duration::from_millis(2000));
+                if let Ok(is_active) = auto_refresh.lock() {
+                    if *is_active {
+                        sender.send(AppEvent::Action(Actions::RefreshProcesses)).unwrap();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    fn exit(&mut self) {
+        self.set_auto_refresh(false);
+        self.service = None;
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        let keymap = self.keymap.clone();
+        if let Some(offset) = keymap.offset(&key) {
+            self.select(offset);
+        } else if keymap.is(&key, KeyAction::Refresh) {
+            self.refresh();
+        } else if keymap.is(&key, KeyAction::Kill) {
+            if let Some(process) = self.selected() {
+                self.sender.send(AppEvent::Action(Actions::OpenProcessKillDialog(process.pid))).unwrap();
+            }
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.exit();
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(service) = &self.service else {
+            return;
+        };
+
+        let cgroup = self.selected().map(|process| format!(" {} ", process.cgroup)).unwrap_or_default();
+        let rows: Vec<Row<'_>> = process_tree(&self.processes)
+            .into_iter()
+            .map(|(depth, process)| {
+                let branch = if depth == 0 { String::new() } else { format!("{}└─ ", "   ".repeat(depth - 1)) };
+                let cpu = self.cpu.get(&process.pid).map_or_else(|| "-".to_string(), |cpu| format!("{cpu:.1}"));
+                let command = if process.command.is_empty() { "-" } else { process.command.as_str() };
+                Row::new(vec![
+                    Cell::from(process.pid.to_string()).style(Style::default().fg(theme().accent)),
+                    Cell::from(process.user.clone()),
+                    Cell::from(cpu),
+                    Cell::from(format_bytes(process.rss_bytes)),
+                    Cell::from(Line::from(vec![
+                        Span::styled(branch, Style::default().fg(theme().dim)),
+                        Span::raw(command.to_string()),
+                    ])),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Length(12),
+                Constraint::Length(6),
+                Constraint::Length(8),
+                Constraint::Min(0),
+            ],
+        )
+        .header(
+            Row::new(["PID", "User", "CPU%", "RSS", "Command"])
+                .style(Style::default().fg(theme().muted).add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(1))
+                .title(format!(" Processes of {} ({}) ", service.name(), self.processes.len()))
+                .title_alignment(Alignment::Center)
+                .title_bottom(Line::styled(cgroup, Style::default().fg(theme().muted))),
+        )
+        .row_highlight_style(theme().selection())
+        .highlight_symbol(">> ");
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        shortcut_lines(&[
+            ("Navigate", keymap.move_keys()),
+            ("Send signal", keymap.key(KeyAction::Kill)),
+            ("Refresh", keymap.key(KeyAction::Refresh)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
+    }
+}
@@ -0,0 +1,205 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::unit_resources::ResourceControl;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::input::TextInput;
+use crate::terminal::components::list::ServiceAction;
+use crate::terminal::components::popup::centered_area;
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+
+const LABEL_WIDTH: usize = 12;
+
+struct ResourceField {
+    control: ResourceControl,
+    /// Value when the dialog was opened, so only edited settings are sent.
+    initial: String,
+    input: TextInput,
+}
+
+pub struct ResourcesDialog {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    service: Option<Service>,
+    fields: Vec<ResourceField>,
+    /// Index in `fields`, one past the end selects the runtime/persistent toggle.
+    focus: usize,
+    runtime: bool,
+    message: Option<String>,
+}
+
+impl ResourcesDialog {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            service: None,
+            fields: Vec::new(),
+            focus: 0,
+            runtime: true,
+            message: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.service.is_some()
+    }
+
+    pub fn open(&mut self, service: Service) {
+        let result = self.usecase.borrow().resource_controls(&service);
+        match result {
+            Ok(values) => {
+                self.fields = values
+                    .into_iter()
+                    .map(|(control, value)| {
+                        let initial = control.format(value);
+                        ResourceField { control, input: TextInput::new(&initial), initial }
+                    })
+                    .collect();
+                self.service = Some(service);
+                self.focus = 0;
+                self.runtime = true;
+                self.message = None;
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        self.service = None;
+    }
+
+    fn on_toggle(&self) -> bool {
+        self.focus == self.fields.len()
+    }
+
+    fn apply(&mut self) {
+        let mut values = Vec::new();
+        for field in self.fields.iter().filter(|field| field.input.value().trim() != field.initial) {
+            match field.control.parse(field.input.value()) {
+                Ok(value) => values.push((field.control, value)),
+                Err(message) => {
+                    self.message = Some(message);
+                    return;
+                }
+            }
+        }
+        if values.is_empty() {
+            self.close();
+            return;
+        }
+        let Some(service) = self.service.take() else {
+            return;
+        };
+
+        let result = self.usecase.borrow().set_resource_controls(&service, self.runtime, &values);
+        match result {
+            Ok(()) => {
+                self.sender.send(AppEvent::Action(Actions::ServiceAction(ServiceAction::RefreshAll))).unwrap();
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        self.message = None;
+        match key.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Enter => self.apply(),
+            KeyCode::Tab | KeyCode::Down => self.focus = (self.focus + 1) % (self.fields.len() + 1),
+            KeyCode::BackTab | KeyCode::Up => self.focus = (self.focus + self.fields.len()) % (self.fields.len() + 1),
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') if self.on_toggle() => self.runtime = !self.runtime,
+            _ => {
+                if let Some(field) = self.fields.get_mut(self.focus) {
+                    field.input.on_key_event(key);
+                }
+            }
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let Some(service) = &self.service else {
+            return;
+        };
+
+        let heading = Style::default().fg(theme().heading).add_modifier(Modifier::BOLD);
+        let mut lines = Vec::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            let label_style = if index == self.focus { heading } else { Style::default().fg(theme().muted) };
+            let value = if field.input.value().is_empty() {
+                Span::styled(field.control.hint(), Style::default().fg(theme().dim))
+            } else if field.input.value().trim() == field.initial {
+                Span::raw(field.input.value().to_string())
+            } else {
+                Span::styled(field.input.value().to_string(), Style::default().fg(theme().accent))
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<LABEL_WIDTH$}", field.control.directive()), label_style),
+                value,
+            ]));
+        }
+        lines.push(Line::raw(""));
+
+        let label_style = if self.on_toggle() { heading } else { Style::default().fg(theme().muted) };
+        let mut toggle = vec![Span::styled(format!("{:<LABEL_WIDTH$}", "Apply"), label_style)];
+        for (runtime, label) in [(true, "runtime"), (false, "persistent")] {
+            let style = if runtime == self.runtime {
+                Style::default().fg(theme().on_accent).bg(theme().accent)
+            } else {
+                Style::default().fg(theme().muted)
+            };
+            toggle.push(Span::styled(format!(" {label} "), style));
+            toggle.push(Span::raw(" "));
+        }
+        lines.push(Line::from(toggle));
+        lines.push(Line::styled(
+            if self.runtime { "Lost on reboot or daemon-reload." } else { "Written to a drop-in under system.control." },
+            Style::default().fg(theme().dim),
+        ));
+        lines.push(Line::raw(""));
+
+        if let Some(message) = &self.message {
+            lines.push(Line::styled(message.clone(), Style::default().fg(theme().error)));
+        } else {
+            lines.push(Line::styled(
+                "↑/↓: field | ←/→: runtime/persistent | Enter: apply | Esc: cancel",
+                Style::default().fg(theme().muted),
+            ));
+        }
+
+        let popup_area = centered_area(area, 64, u16::try_from(lines.len() + 2).unwrap_or(u16::MAX));
+        frame.render_widget(Clear, popup_area);
+        let popup = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme().accent))
+                .padding(Padding::horizontal(1))
+                .title(format!(" Resource limits of {} ", service.name())),
+        );
+        frame.render_widget(popup, popup_area);
+
+        if let Some(field) = self.fields.get(self.focus) {
+            #[allow(clippy::cast_possible_truncation)]
+            frame.set_cursor_position(Position::new(
+                popup_area.x + 2 + (LABEL_WIDTH + field.input.cursor()) as u16,
+                popup_area.y + 1 + self.focus as u16,
+            ));
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::transient_unit::{TransientKind, TransientUnit};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::input::TextInput;
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+const LABEL_WIDTH: usize = 19;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+    Kind,
+    Command,
+    Name,
+    WorkingDirectory,
+    Environment,
+    MemoryMax,
+    CpuQuota,
+    OnCalendar,
+}
+
+const FIELDS: [Field; 8] = [
+    Field::Kind,
+    Field::Command,
+    Field::Name,
+    Field::WorkingDirectory,
+    Field::Environment,
+    Field::MemoryMax,
+    Field::CpuQuota,
+    Field::OnCalendar,
+];
+
+pub struct RunDialog {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    kind: TransientKind,
+    command: TextInput,
+    name: TextInput,
+    working_directory: TextInput,
+    environment: TextInput,
+    memory_max: TextInput,
+    cpu_quota: TextInput,
+    on_calendar: TextInput,
+    focus: Field,
+    message: Option<String>,
+}
+
+impl RunDialog {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            kind: TransientKind::Service,
+            command: TextInput::default(),
+            name: TextInput::default(),
+            working_directory: TextInput::default(),
+            environment: TextInput::default(),
+            memory_max: TextInput::default(),
+            cpu_quota: TextInput::default(),
+            on_calendar: TextInput::default(),
+            focus: Field::Command,
+            message: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        let sender = self.sender.clone();
+        let usecase = self.usecase.clone();
+        *self = Self::new(sender, usecase);
+    }
+
+    fn unit(&self) -> TransientUnit {
+        TransientUnit {
+            kind: self.kind,
+            name: self.name.value().to_string(),
+            command: self.command.value().to_string(),
+            working_directory: self.working_directory.value().to_string(),
+            environment: self.environment.value().to_string(),
+            memory_max: self.memory_max.value().to_string(),
+            cpu_quota: self.cpu_quota.value().to_string(),
+            on_calendar: self.on_calendar.value().to_string(),
+        }
+    }
+
+    fn visible_fields(&self) -> Vec<Field> {
+        FIELDS
+            .iter()
+            .copied()
+            .filter(|field| *field != Field::OnCalendar || self.kind == TransientKind::Service)
+            .collect()
+    }
+
+    fn label(field: Field) -> &'static str {
+        match field {
+            Field::Kind => "Type",
+            Field::Command => "Command",
+            Field::Name => "Name",
+            Field::WorkingDirectory => "Working directory",
+            Field::Environment => "Environment",
+            Field::MemoryMax => "MemoryMax",
+            Field::CpuQuota => "CPUQuota",
+            Field::OnCalendar => "Timer (OnCalendar)",
+        }
+    }
+
+    fn placeholder(field: Field) -> &'static str {
+        match field {
+            Field::Name => "generated when empty",
+            Field::WorkingDirectory => "/srv/app",
+            Field::Environment => "KEY=value OTHER=value",
+            Field::MemoryMax => "512M",
+            Field::CpuQuota => "50%",
+            Field::OnCalendar => "run now when empty, or e.g. hourly",
+            Field::Kind | Field::Command => "",
+        }
+    }
+
+    fn input(&self, field: Field) -> Option<&TextInput> {
+        match field {
+            Field::Command => Some(&self.command),
+            Field::Name => Some(&self.name),
+            Field::WorkingDirectory => Some(&self.working_directory),
+            Field::Environment => Some(&self.environment),
+            Field::MemoryMax => Some(&self.memory_max),
+            Field::CpuQuota => Some(&self.cpu_quota),
+            Field::OnCalendar => Some(&self.on_calendar),
+            Field::Kind => None,
+        }
+    }
+
+    fn input_mut(&mut self, field: Field) -> Option<&mut TextInput> {
+        match field {
+            Field::Command => Some(&mut self.command),
+            Field::Name => Some(&mut self.name),
+            Field::WorkingDirectory => Some(&mut self.working_directory),
+            Field::Environment => Some(&mut self.environment),
+            Field::MemoryMax => Some(&mut self.memory_max),
+            Field::CpuQuota => Some(&mut self.cpu_quota),
+            Field::OnCalendar => Some(&mut self.on_calendar),
+            Field::Kind => None,
+        }
+    }
+
+    fn move_focus(&mut self, forward: bool) {
+        let fields = self.visible_fields();
+        let index = fields.iter().position(|field| *field == self.focus).unwrap_or(0);
+        let next = if forward {
+            (index + 1) % fields.len()
+        } else {
+            (index + fields.len() - 1) % fields.len()
+        };
+        self.focus = fields[next];
+    }
+
+    fn cycle_kind(&mut self) {
+        let index = TransientKind::ALL.iter().position(|kind| *kind == self.kind).unwrap_or(0);
+        self.kind = TransientKind::ALL[(index + 1) % TransientKind::ALL.len()];
+    }
+
+    /// The `systemd-run` invocation doing the same, shown so the command can be reused in scripts.
+    fn equivalent_command(&self) -> String {
+        let unit = self.unit();
+        let mut parts = vec!["systemd-run".to_string()];
+        if self.kind == TransientKind::Scope {
+            parts.push("--scope".to_string());
+        }
+        if !unit.name.trim().is_empty() {
+            parts.push(format!("--unit={}", unit.name.trim()));
+        }
+        if !unit.working_directory.trim().is_empty() {
+            parts.push(format!("--working-directory={}", unit.working_directory.trim()));
+        }
+        parts.extend(unit.environment().into_iter().map(|assignment| format!("--setenv={assignment}")));
+        if !unit.memory_max.trim().is_empty() {
+            parts.push(format!("--property=MemoryMax={}", unit.memory_max.trim()));
+        }
+        if !unit.cpu_quota.trim().is_empty() {
+            parts.push(format!("--property=CPUQuota={}", unit.cpu_quota.trim()));
+        }
+        if self.kind == TransientKind::Service && !unit.on_calendar.trim().is_empty() {
+            parts.push(format!("--on-calendar={}", unit.on_calendar.trim()));
+        }
+        parts.push(unit.command.trim().to_string());
+        parts.join(" ")
+    }
+
+    fn run(&mut self) {
+        let unit = self.unit();
+        if let Err(message) = unit.validate() {
+            self.message = Some(message);
+            return;
+        }
+
+        let result = self.usecase.borrow().run_transient_unit(&unit);
+        match result {
+            Ok(service) => {
+                self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+                self.sender.send(AppEvent::Action(Actions::FocusService(service))).unwrap();
+                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        self.message = None;
+
+        match key.code {
+            KeyCode::Esc => {
+                self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => self.run(),
+            KeyCode::Tab | KeyCode::Down | KeyCode::Enter => self.move_focus(true),
+            KeyCode::BackTab | KeyCode::Up => self.move_focus(false),
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') if self.focus == Field::Kind => self.cycle_kind(),
+            _ => {
+                if let Some(input) = self.input_mut(self.focus) {
+                    input.on_key_event(key);
+                }
+            }
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let mut lines: Vec<Line<'_>> = Vec::new();
+        let mut cursor = None;
+        for (row, field) in self.visible_fields().into_iter().enumerate() {
+            let focused = field == self.focus;
+            let label_style = if focused {
+                Style::default().fg(theme().heading).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().muted)
+            };
+            let label = Span::styled(format!("{:<LABEL_WIDTH$}", Self::label(field)), label_style);
+
+            let value = match self.input(field) {
+                Some(input) => {
+                    if focused {
+                        cursor = Some((row, input.cursor()));
+                    }
+                    if input.value().is_empty() {
+                        Span::styled(Self::placeholder(field), Style::default().fg(theme().dim))
+                    } else {
+                        Span::raw(input.value().to_string())
+                    }
+                }
+                None if focused => Span::styled(format!("< {} >", self.kind.as_str()), Style::default().fg(theme().accent)),
+                None => Span::raw(self.kind.as_str()),
+            };
+            lines.push(Line::from(vec![label, value]));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(self.equivalent_command(), Style::default().fg(theme().dim)));
+        if self.kind == TransientKind::Scope {
+            lines.push(Line::styled(
+                "Scopes run the command from here; its output goes to the journal.",
+                Style::default().fg(theme().dim),
+            ));
+        }
+        if let Some(message) = &self.message {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(message.clone(), Style::default().fg(theme().error)));
+        }
+
+        let form = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(1))
+                .title(" Run command ")
+                .title_alignment(Alignment::Center),
+        );
+        frame.render_widget(form, area);
+
+        if let Some((row, column)) = cursor {
+            #[allow(clippy::cast_possible_truncation)]
+            frame.set_cursor_position(Position::new(
+                area.x + 2 + (LABEL_WIDTH + column) as u16,
+                area.y + 1 + row as u16,
+            ));
+        }
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        shortcut_lines(&[
+            ("Next field", "Tab/↓".to_string()),
+            ("Previous field", "Shift+Tab/↑".to_string()),
+            ("Service/Scope", "←/→".to_string()),
+            ("Run and follow the log", "Ctrl+r".to_string()),
+            ("Cancel", "Esc".to_string()),
+        ])
+    }
+}
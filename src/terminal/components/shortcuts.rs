@@ -0,0 +1,20 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::terminal::theme::theme;
+
+/// Shortcut bar of a screen: the "Actions" title and its entries, given as (label, keys) pairs.
+pub fn shortcut_lines(entries: &[(&str, String)]) -> Vec<Line<'static>> {
+    let entries: Vec<String> = entries.iter().map(|(label, keys)| format!("{label}: {keys}")).collect();
+    vec![
+        Line::from(Span::styled(
+            "Actions",
+            Style::default()
+                .fg(theme().shortcut_heading)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(entries.join(" | ")),
+    ]
+}
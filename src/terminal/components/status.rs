@@ -0,0 +1,269 @@
+use chrono::{DateTime, Local};
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::unit_process::{process_tree, UnitProcess};
+use crate::domain::unit_status::{elapsed_since, format_bytes, format_duration, UnitStatus};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+const JOURNAL_LINES: usize = 10;
+const LABEL_WIDTH: usize = 11;
+
+fn active_color(active_state: &str) -> Color {
+    match active_state {
+        "active" | "reloading" => theme().success,
+        "failed" => theme().error,
+        "activating" | "deactivating" => theme().warning,
+        _ => theme().muted,
+    }
+}
+
+fn format_timestamp(timestamp_usec: u64) -> Option<String> {
+    let timestamp = DateTime::from_timestamp_micros(i64::try_from(timestamp_usec).ok()?)?;
+    Some(timestamp.with_timezone(&Local).format("%a %Y-%m-%d %H:%M:%S %Z").to_string())
+}
+
+fn format_cpu(nsec: u64) -> String {
+    let secs = nsec / 1_000_000_000;
+    if secs < 60 {
+        format!("{}.{:03}s", secs, (nsec / 1_000_000) % 1000)
+    } else {
+        format_duration(secs)
+    }
+}
+
+fn field<'a>(label: &str, value: Vec<Span<'a>>) -> Line<'a> {
+    let mut spans = vec![Span::styled(
+        format!("{label:>LABEL_WIDTH$}: "),
+        Style::default().fg(theme().heading).add_modifier(Modifier::BOLD),
+    )];
+    spans.extend(value);
+    Line::from(spans)
+}
+
+pub struct ServiceStatus {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    service: Option<Service>,
+    status: Option<UnitStatus>,
+    processes: Vec<UnitProcess>,
+    journal: String,
+    scroll: u16,
+    keymap: Rc<Keymap>,
+}
+
+impl ServiceStatus {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            service: None,
+            status: None,
+            processes: Vec::new(),
+            journal: String::new(),
+            scroll: 0,
+            keymap: Rc::new(Keymap::default()),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
+    pub fn open(&mut self, service: Service) {
+        self.service = Some(service);
+        self.scroll = 0;
+        self.refresh();
+    }
+
+    pub fn refresh(&mut self) {
+        let Some(service) = &self.service else {
+            return;
+        };
+
+        let usecase = self.usecase.borrow();
+        match usecase.unit_status(service) {
+            Ok(status) => self.status = Some(status),
+            Err(e) => {
+                self.status = None;
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+                return;
+            }
+        }
+        // Units without a cgroup, such as timers or targets, have no processes to list.
+        self.processes = usecase.unit_processes(service).unwrap_or_default();
+        self.journal = usecase.get_log_tail(service, JOURNAL_LINES).unwrap_or_default();
+    }
+
+    /// Opens another unit screen and comes back here when it is closed.
+    fn go(&self, action: Actions) {
+        let Some(service) = self.service.clone() else {
+            return;
+        };
+        self.sender.send(AppEvent::Action(Actions::FocusService(service))).unwrap();
+        self.sender.send(AppEvent::Action(action)).unwrap();
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        let keymap = self.keymap.clone();
+        if keymap.is(&key, KeyAction::MoveDown) {
+            self.scroll = self.scroll.saturating_add(1);
+        } else if keymap.is(&key, KeyAction::MoveUp) {
+            self.scroll = self.scroll.saturating_sub(1);
+        } else if keymap.is(&key, KeyAction::PageDown) {
+            self.scroll = self.scroll.saturating_add(10);
+        } else if keymap.is(&key, KeyAction::PageUp) {
+            self.scroll = self.scroll.saturating_sub(10);
+        } else if keymap.is(&key, KeyAction::Refresh) {
+            self.refresh();
+        } else if keymap.is(&key, KeyAction::Log) {
+            self.go(Actions::GoLog);
+        } else if keymap.is(&key, KeyAction::Details) {
+            self.go(Actions::GoDetails);
+        } else if keymap.is(&key, KeyAction::Processes) {
+            self.go(Actions::GoProcesses);
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.service = None;
+            self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+        }
+    }
+
+    fn cgroup_lines(&self, control_group: &str) -> Vec<Line<'static>> {
+        let indent = " ".repeat(LABEL_WIDTH + 2);
+        let branch = Style::default().fg(theme().dim);
+
+        let mut cgroups: Vec<&str> = self.processes.iter().map(|process| process.cgroup.as_str()).collect();
+        cgroups.sort_unstable();
+        cgroups.dedup();
+
+        let mut lines = Vec::new();
+        for (group_index, cgroup) in cgroups.iter().enumerate() {
+            let last_group = group_index + 1 == cgroups.len();
+            let nested = *cgroup != control_group;
+            let prefix = if nested {
+                let relative = cgroup.strip_prefix(control_group).unwrap_or(cgroup).trim_start_matches('/');
+                lines.push(Line::from(vec![
+                    Span::raw(indent.clone()),
+                    Span::styled(if last_group { "└─" } else { "├─" }, branch),
+                    Span::raw(relative.to_string()),
+                ]));
+                format!("{indent}{}", if last_group { "  " } else { "│ " })
+            } else {
+                indent.clone()
+            };
+
+            let members: Vec<UnitProcess> =
+                self.processes.iter().filter(|process| process.cgroup == *cgroup).cloned().collect();
+            let tree = process_tree(&members);
+            for (index, (depth, process)) in tree.iter().enumerate() {
+                let last = index + 1 == tree.len() && (nested || last_group);
+                let connector = if last { "└─" } else { "├─" };
+                lines.push(Line::from(vec![
+                    Span::raw(prefix.clone()),
+                    Span::styled(format!("{}{connector}", "  ".repeat(*depth)), branch),
+                    Span::styled(process.pid.to_string(), Style::default().fg(theme().accent)),
+                    Span::raw(format!(" {}", process.command)),
+                ]));
+            }
+        }
+        lines
+    }
+
+    fn status_lines(&self, status: &UnitStatus) -> Vec<Line<'static>> {
+        let color = active_color(&status.active_state);
+        let mut lines = vec![Line::from(vec![
+            Span::styled("● ", Style::default().fg(color)),
+            Span::styled(status.name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" - {}", status.description)),
+        ])];
+
+        let mut loaded = vec![status.fragment_path.clone(), status.unit_file_state.clone()];
+        if !status.unit_file_preset.is_empty() {
+            loaded.push(format!("preset: {}", status.unit_file_preset));
+        }
+        loaded.retain(|part| !part.is_empty());
+        let loaded = if loaded.is_empty() { String::new() } else { format!(" ({})", loaded.join("; ")) };
+        lines.push(field("Loaded", vec![Span::raw(format!("{}{loaded}", status.load_state))]));
+
+        let mut active = vec![Span::styled(
+            format!("{} ({})", status.active_state, status.sub_state),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )];
+        if let Some(since) = format_timestamp(status.state_change_timestamp).filter(|_| status.state_change_timestamp > 0) {
+            active.push(Span::raw(format!(" since {since}; {} ago", elapsed_since(status.state_change_timestamp))));
+        }
+        lines.push(field("Active", active));
+
+        if let Some(pid) = status.main_pid {
+            let command = self
+                .processes
+                .iter()
+                .find(|process| process.pid == pid)
+                .and_then(|process| process.command.split_whitespace().next())
+                .and_then(|program| program.rsplit('/').next())
+                .map(|program| format!(" ({program})"))
+                .unwrap_or_default();
+            lines.push(field("Main PID", vec![Span::raw(format!("{pid}{command}"))]));
+        }
+        if let Some(tasks) = status.tasks_current {
+            let limit = status.tasks_max.map(|max| format!(" (limit: {max})")).unwrap_or_default();
+            lines.push(field("Tasks", vec![Span::raw(format!("{tasks}{limit}"))]));
+        }
+        if let Some(memory) = status.memory_current {
+            lines.push(field("Memory", vec![Span::raw(format_bytes(memory))]));
+        }
+        if let Some(cpu) = status.cpu_usage_nsec {
+            lines.push(field("CPU", vec![Span::raw(format_cpu(cpu))]));
+        }
+        if let Some(control_group) = &status.control_group {
+            lines.push(field("CGroup", vec![Span::raw(control_group.clone())]));
+            lines.extend(self.cgroup_lines(control_group));
+        }
+
+        lines.push(Line::raw(""));
+        lines.extend(self.journal.lines().map(|line| Line::styled(line.to_string(), Style::default().fg(theme().muted))));
+        lines
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(service) = &self.service else {
+            return;
+        };
+        let lines = self.status.as_ref().map(|status| self.status_lines(status)).unwrap_or_default();
+
+        let paragraph = Paragraph::new(lines).scroll((self.scroll, 0)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .padding(Padding::horizontal(1))
+                .title(format!(" Status of {} ", service.name()))
+                .title_alignment(Alignment::Center),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        shortcut_lines(&[
+            ("Scroll", keymap.move_keys()),
+            ("Log", keymap.key(KeyAction::Log)),
+            ("Unit File", keymap.key(KeyAction::Details)),
+            ("Processes", keymap.key(KeyAction::Processes)),
+            ("Refresh", keymap.key(KeyAction::Refresh)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
+    }
+}
@@ -0,0 +1,322 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Clear, Padding, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::service_state::ServiceState;
+use crate::domain::unit_target::TargetUnit;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::input::TextInput;
+use crate::terminal::components::popup::centered_area;
+use crate::terminal::keymap::{KeyAction, Keymap, ViewAction};
+use crate::usecases::services_manager::ServicesManager;
+use crate::terminal::theme::theme;
+use crate::terminal::components::shortcuts::shortcut_lines;
+
+enum Confirm {
+    SetDefault(String),
+    /// Isolating is only confirmed once the target name has been typed back.
+    Isolate(String, TextInput),
+}
+
+pub struct TargetsView {
+    sender: Sender<AppEvent>,
+    usecase: Rc<RefCell<ServicesManager>>,
+    targets: Vec<TargetUnit>,
+    default_target: String,
+    table_state: TableState,
+    confirm: Option<Confirm>,
+    keymap: Rc<Keymap>,
+}
+
+impl TargetsView {
+    pub fn new(sender: Sender<AppEvent>, usecase: Rc<RefCell<ServicesManager>>) -> Self {
+        Self {
+            sender,
+            usecase,
+            targets: Vec::new(),
+            default_target: String::new(),
+            table_state: TableState::default(),
+            confirm: None,
+            keymap: Rc::new(Keymap::default()),
+        }
+    }
+
+    pub fn set_keymap(&mut self, keymap: Rc<Keymap>) {
+        self.keymap = keymap;
+    }
+
+    pub fn refresh(&mut self) {
+        let usecase = self.usecase.borrow();
+        match usecase.list_targets() {
+            Ok(targets) => {
+                self.targets = targets;
+                self.default_target = usecase.default_target().unwrap_or_default();
+            }
+            Err(e) => {
+                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+            }
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select((!self.targets.is_empty()).then(|| selected.min(self.targets.len() - 1)));
+    }
+
+    fn selected(&self) -> Option<&TargetUnit> {
+        self.table_state.selected().and_then(|index| self.targets.get(index))
+    }
+
+    fn select(&mut self, offset: isize) {
+        let len = self.targets.len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select(Some(selected.saturating_add_signed(offset).min(len - 1)));
+    }
+
+    fn open_selected(&self) {
+        let Some(target) = self.selected() else {
+            return;
+        };
+        let service = self.usecase.borrow().get_unit(&target.name).unwrap_or_else(|_| {
+            Service::new(target.name.clone(), target.description.clone(), ServiceState::default())
+        });
+        self.sender.send(AppEvent::Action(Actions::FocusService(service))).unwrap();
+        self.sender.send(AppEvent::Action(Actions::GoStatus)).unwrap();
+    }
+
+    fn run_confirmed(&mut self) {
+        let Some(confirm) = self.confirm.take() else {
+            return;
+        };
+        let result = match &confirm {
+            Confirm::SetDefault(name) => self.usecase.borrow().set_default_target(name),
+            Confirm::Isolate(name, input) => {
+                if input.value() != name {
+                    self.confirm = Some(confirm);
+                    return;
+                }
+                self.usecase.borrow().isolate_target(name).map(|_| ())
+            }
+        };
+        if let Err(e) = result {
+            self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+        }
+        self.refresh();
+    }
+
+    fn on_confirm_key_event(&mut self, key: KeyEvent) {
+        let keymap = self.keymap.clone();
+        match &mut self.confirm {
+            // The target name is typed, so only keys that do not type text confirm or cancel.
+            Some(Confirm::Isolate(_, input)) => {
+                if keymap.is_view_command(&key, ViewAction::Cancel) {
+                    self.confirm = None;
+                } else if keymap.is_view_command(&key, ViewAction::Confirm) {
+                    self.run_confirmed();
+                } else {
+                    input.on_key_event(key);
+                }
+            }
+            Some(Confirm::SetDefault(_)) => {
+                if keymap.is_view(&key, ViewAction::Cancel) {
+                    self.confirm = None;
+                } else if keymap.is_view(&key, ViewAction::Confirm) {
+                    self.run_confirmed();
+                }
+            }
+            None => {}
+        }
+    }
+
+    pub fn on_key_event(&mut self, key: KeyEvent) {
+        if self.confirm.is_some() {
+            self.on_confirm_key_event(key);
+            return;
+        }
+
+        let keymap = self.keymap.clone();
+        if let Some(offset) = keymap.offset(&key) {
+            self.select(offset);
+        } else if keymap.is_view(&key, ViewAction::Open) {
+            self.open_selected();
+        } else if keymap.is_view(&key, ViewAction::SetDefault) {
+            if let Some(target) = self.selected() {
+                self.confirm = Some(Confirm::SetDefault(target.name.clone()));
+            }
+        } else if keymap.is_view(&key, ViewAction::Isolate) {
+            if let Some(target) = self.selected().filter(|target| target.allow_isolate) {
+                self.confirm = Some(Confirm::Isolate(target.name.clone(), TextInput::default()));
+            }
+        } else if keymap.is(&key, KeyAction::Refresh) {
+            self.refresh();
+        } else if keymap.is_view(&key, ViewAction::Back) {
+            self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let [summary_area, table_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+        let active = self.targets.iter().filter(|target| target.active_state == "active").count();
+        let summary = Paragraph::new(Line::from(vec![
+            Span::raw(" Default target: "),
+            Span::styled(self.default_target.clone(), Style::default().fg(theme().accent).add_modifier(Modifier::BOLD)),
+            Span::raw(format!("  |  {active} of {} targets active", self.targets.len())),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Targets ")
+                .title_alignment(Alignment::Center),
+        );
+        frame.render_widget(summary, summary_area);
+
+        let rows: Vec<Row<'_>> = self
+            .targets
+            .iter()
+            .map(|target| {
+                let state_style = match target.active_state.as_str() {
+                    "active" => Style::default().fg(theme().success),
+                    "inactive" => Style::default().fg(theme().dim),
+                    "failed" => Style::default().fg(theme().error),
+                    _ => Style::default().fg(theme().warning),
+                };
+                let default = if target.name == self.default_target { "default" } else { "" };
+                Row::new(vec![
+                    Cell::from(target.name.clone()).style(Style::default().fg(theme().accent).add_modifier(Modifier::BOLD)),
+                    Cell::from(target.active_state.clone()).style(state_style),
+                    Cell::from(default).style(Style::default().fg(theme().warning)),
+                    Cell::from(if target.allow_isolate { "yes" } else { "no" }),
+                    Cell::from(target.description.clone()).style(Style::default().fg(theme().muted)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(25),
+                Constraint::Length(12),
+                Constraint::Length(9),
+                Constraint::Length(9),
+                Constraint::Min(0),
+            ],
+        )
+        .header(
+            Row::new(["Name", "Active", "Default", "Isolate", "Description"])
+                .style(Style::default().fg(theme().muted).add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::default().borders(Borders::ALL).padding(Padding::horizontal(1)))
+        .row_highlight_style(theme().selection())
+        .highlight_symbol(">> ");
+        frame.render_stateful_widget(table, table_area, &mut self.table_state);
+
+        self.render_confirm(frame, area);
+    }
+
+    fn render_confirm(&self, frame: &mut Frame, area: Rect) {
+        let Some(confirm) = &self.confirm else {
+            return;
+        };
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let (title, border, lines) = match confirm {
+            Confirm::SetDefault(name) => (
+                " Set default target ",
+                theme().accent,
+                vec![
+                    Line::from(vec![
+                        Span::raw("Boot into "),
+                        Span::styled(name.clone(), bold),
+                        Span::raw(" by default?"),
+                    ]),
+                    Line::raw(""),
+                    Line::styled(self.keymap.confirmation_hint(), Style::default().fg(theme().muted)),
+                ],
+            ),
+            Confirm::Isolate(name, input) => {
+                let matches = input.value() == name;
+                (
+                    " Isolate target ",
+                    theme().error,
+                    vec![
+                        Line::from(vec![Span::raw("Isolate "), Span::styled(name.clone(), bold), Span::raw("?")]),
+                        Line::raw(""),
+                        Line::raw("Type the target name to confirm:"),
+                        Line::styled(
+                            input.value().to_string(),
+                            Style::default().fg(if matches { theme().success } else { theme().error }),
+                        ),
+                        Line::raw(""),
+                        Line::styled(
+                            "This starts the target and stops every unit it does not depend on, including services and sessions in use.",
+                            Style::default().fg(theme().warning),
+                        ),
+                        Line::raw(""),
+                        Line::styled(
+                            if matches {
+                                format!(
+                                    "{}: isolate | {}: cancel",
+                                    self.keymap.view_command_keys(ViewAction::Confirm),
+                                    self.keymap.view_command_keys(ViewAction::Cancel)
+                                )
+                            } else {
+                                format!("{}: cancel", self.keymap.view_command_keys(ViewAction::Cancel))
+                            },
+                            Style::default().fg(theme().muted),
+                        ),
+                    ],
+                )
+            }
+        };
+
+        let popup_area = centered_area(area, 64, u16::try_from(lines.len() + 4).unwrap_or(u16::MAX));
+        frame.render_widget(Clear, popup_area);
+        let popup = Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(border))
+                    .padding(Padding::horizontal(1))
+                    .title(title),
+            );
+        frame.render_widget(popup, popup_area);
+
+        if let Confirm::Isolate(_, input) = confirm {
+            #[allow(clippy::cast_possible_truncation)]
+            frame.set_cursor_position(Position::new(
+                popup_area.x + 2 + input.cursor() as u16,
+                popup_area.y + 4,
+            ));
+        }
+    }
+
+    pub fn shortcuts(&self) -> Vec<Line<'_>> {
+        let keymap = &self.keymap;
+        let isolate = if self.selected().is_some_and(|target| target.allow_isolate) {
+            keymap.view_key(ViewAction::Isolate)
+        } else {
+            "n/a".to_string()
+        };
+        shortcut_lines(&[
+            ("Navigate", keymap.move_keys()),
+            ("Set default", keymap.view_key(ViewAction::SetDefault)),
+            ("Isolate", isolate),
+            ("Unit status", keymap.view_key(ViewAction::Open)),
+            ("Refresh", keymap.key(KeyAction::Refresh)),
+            ("Go back", keymap.view_keys(ViewAction::Back)),
+        ])
+    }
+}
@@ -0,0 +1,878 @@
+use crate::terminal::theme::theme;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Commands of the unit list that can be bound to keys in the `[keys]` table of the config file.
+/// The other screens follow the same keys to move, switch panes, refresh and open views.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyAction {
+    MoveUp,
+    MoveDown,
+    PreviousTab,
+    NextTab,
+    PageUp,
+    PageDown,
+    Start,
+    Stop,
+    Restart,
+    Reload,
+    ReloadOrRestart,
+    TryRestart,
+    ReloadOrTryRestart,
+    Enable,
+    Disable,
+    ToggleMask,
+    Kill,
+    ResourceLimits,
+    ResetFailed,
+    ResetAllFailed,
+    JobMode,
+    Filter,
+    ToggleUnitFilter,
+    CycleStateFilter,
+    Refresh,
+    Hosts,
+    Exposure,
+    Status,
+    Log,
+    Details,
+    NewUnit,
+    Run,
+    Overrides,
+    Processes,
+    Boot,
+    Targets,
+    Jobs,
+    Help,
+    Suspend,
+    Quit,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum HelpSection {
+    Navigation,
+    ServiceControl,
+    ViewAndFilter,
+    Information,
+    Application,
+}
+
+impl HelpSection {
+    const ALL: [HelpSection; 5] = [
+        HelpSection::Navigation,
+        HelpSection::ServiceControl,
+        HelpSection::ViewAndFilter,
+        HelpSection::Information,
+        HelpSection::Application,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            HelpSection::Navigation => "Navigation:",
+            HelpSection::ServiceControl => "Service Control:",
+            HelpSection::ViewAndFilter => "View & Filter list:",
+            HelpSection::Information => "Information:",
+            HelpSection::Application => "Application:",
+        }
+    }
+}
+
+impl KeyAction {
+    /// Every action, in the order of the help popup and the shortcut bar.
+    pub const ALL: [KeyAction; 40] = [
+        KeyAction::MoveUp,
+        KeyAction::MoveDown,
+        KeyAction::PreviousTab,
+        KeyAction::NextTab,
+        KeyAction::PageUp,
+        KeyAction::PageDown,
+        KeyAction::Start,
+        KeyAction::Stop,
+        KeyAction::Restart,
+        KeyAction::Reload,
+        KeyAction::ReloadOrRestart,
+        KeyAction::TryRestart,
+        KeyAction::ReloadOrTryRestart,
+        KeyAction::Enable,
+        KeyAction::Disable,
+        KeyAction::ToggleMask,
+        KeyAction::Kill,
+        KeyAction::ResourceLimits,
+        KeyAction::ResetFailed,
+        KeyAction::ResetAllFailed,
+        KeyAction::JobMode,
+        KeyAction::Filter,
+        KeyAction::ToggleUnitFilter,
+        KeyAction::CycleStateFilter,
+        KeyAction::Refresh,
+        KeyAction::Hosts,
+        KeyAction::Exposure,
+        KeyAction::Status,
+        KeyAction::Log,
+        KeyAction::Details,
+        KeyAction::NewUnit,
+        KeyAction::Run,
+        KeyAction::Overrides,
+        KeyAction::Processes,
+        KeyAction::Boot,
+        KeyAction::Targets,
+        KeyAction::Jobs,
+        KeyAction::Help,
+        KeyAction::Suspend,
+        KeyAction::Quit,
+    ];
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            KeyAction::MoveUp => &["up", "k"],
+            KeyAction::MoveDown => &["down", "j"],
+            KeyAction::PreviousTab => &["left", "h"],
+            KeyAction::NextTab => &["right", "l"],
+            KeyAction::PageUp => &["pageup"],
+            KeyAction::PageDown => &["pagedown"],
+            KeyAction::Start => &["s"],
+            KeyAction::Stop => &["x"],
+            KeyAction::Restart => &["r"],
+            KeyAction::Reload => &["R"],
+            KeyAction::ReloadOrRestart => &["o"],
+            KeyAction::TryRestart => &["t"],
+            KeyAction::ReloadOrTryRestart => &["T"],
+            KeyAction::Enable => &["e"],
+            KeyAction::Disable => &["d"],
+            KeyAction::ToggleMask => &["m"],
+            KeyAction::Kill => &["K"],
+            KeyAction::ResourceLimits => &["L"],
+            KeyAction::ResetFailed => &["F"],
+            KeyAction::ResetAllFailed => &["ctrl+f"],
+            KeyAction::JobMode => &["M"],
+            KeyAction::Filter => &["i", "/"],
+            KeyAction::ToggleUnitFilter => &["f"],
+            KeyAction::CycleStateFilter => &["a"],
+            KeyAction::Refresh => &["u"],
+            KeyAction::Hosts => &["H"],
+            KeyAction::Exposure => &["S"],
+            KeyAction::Status => &["enter"],
+            KeyAction::Log => &["v"],
+            KeyAction::Details => &["c"],
+            KeyAction::NewUnit => &["n"],
+            KeyAction::Run => &["X"],
+            KeyAction::Overrides => &["D"],
+            KeyAction::Processes => &["p"],
+            KeyAction::Boot => &["B"],
+            KeyAction::Targets => &["g"],
+            KeyAction::Jobs => &["J"],
+            KeyAction::Help => &["?"],
+            KeyAction::Suspend => &["ctrl+z"],
+            KeyAction::Quit => &["ctrl+c"],
+        }
+    }
+
+    fn section(self) -> HelpSection {
+        match self {
+            KeyAction::MoveUp
+            | KeyAction::MoveDown
+            | KeyAction::PreviousTab
+            | KeyAction::NextTab
+            | KeyAction::PageUp
+            | KeyAction::PageDown => HelpSection::Navigation,
+            KeyAction::Start
+            | KeyAction::Stop
+            | KeyAction::Restart
+            | KeyAction::Reload
+            | KeyAction::ReloadOrRestart
+            | KeyAction::TryRestart
+            | KeyAction::ReloadOrTryRestart
+            | KeyAction::Enable
+            | KeyAction::Disable
+            | KeyAction::ToggleMask
+            | KeyAction::Kill
+            | KeyAction::ResourceLimits
+            | KeyAction::ResetFailed
+            | KeyAction::ResetAllFailed
+            | KeyAction::JobMode => HelpSection::ServiceControl,
+            KeyAction::Filter
+            | KeyAction::ToggleUnitFilter
+            | KeyAction::CycleStateFilter
+            | KeyAction::Refresh
+            | KeyAction::Hosts
+            | KeyAction::Exposure => HelpSection::ViewAndFilter,
+            KeyAction::Status
+            | KeyAction::Log
+            | KeyAction::Details
+            | KeyAction::NewUnit
+            | KeyAction::Run
+            | KeyAction::Overrides
+            | KeyAction::Processes
+            | KeyAction::Boot
+            | KeyAction::Targets
+            | KeyAction::Jobs => HelpSection::Information,
+            KeyAction::Help | KeyAction::Suspend | KeyAction::Quit => HelpSection::Application,
+        }
+    }
+
+    /// Text of the help popup.
+    fn description(self) -> &'static str {
+        match self {
+            KeyAction::MoveUp => "Move up",
+            KeyAction::MoveDown => "Move down",
+            KeyAction::PreviousTab => "Previous tab",
+            KeyAction::NextTab => "Next tab",
+            KeyAction::PageUp => "Jump 10 items up",
+            KeyAction::PageDown => "Jump 10 items down",
+            KeyAction::Start => "Start service",
+            KeyAction::Stop => "Stop service",
+            KeyAction::Restart => "Restart service",
+            KeyAction::Reload => "Reload service",
+            KeyAction::ReloadOrRestart => "Reload or restart",
+            KeyAction::TryRestart => "Try restart",
+            KeyAction::ReloadOrTryRestart => "Reload or try restart",
+            KeyAction::Enable => "Enable service",
+            KeyAction::Disable => "Disable service",
+            KeyAction::ToggleMask => "Mask/Unmask service",
+            KeyAction::Kill => "Send a signal to the service processes",
+            KeyAction::ResourceLimits => "Change resource limits (MemoryMax, CPUQuota, TasksMax…)",
+            KeyAction::ResetFailed => "Reset failed state",
+            KeyAction::ResetAllFailed => "Reset all failed units",
+            KeyAction::JobMode => "Cycle the mode of the next job (replace, fail, ignore-dependencies, ignore-requirements)",
+            KeyAction::Filter => "Filter units by name",
+            KeyAction::ToggleUnitFilter => "Toggle all/services filter",
+            KeyAction::CycleStateFilter => "Cycle filter (all→active→inactive→failed)",
+            KeyAction::Refresh => "Refresh service list",
+            KeyAction::Hosts => "Connect to a remote host over ssh or to a local container",
+            KeyAction::Exposure => "Toggle the security exposure column",
+            KeyAction::Status => "Show the service status",
+            KeyAction::Log => "View service logs",
+            KeyAction::Details => "View unit file details",
+            KeyAction::NewUnit => "Create a new unit from a template",
+            KeyAction::Run => "Run a command as a transient service or scope",
+            KeyAction::Overrides => "List overridden, extended and masked units",
+            KeyAction::Processes => "Show the processes of the service",
+            KeyAction::Boot => "Analyze boot time (blame and critical chain)",
+            KeyAction::Targets => "Manage targets (default target, isolate)",
+            KeyAction::Jobs => "Show the job queue (cancel, clear)",
+            KeyAction::Help => "Show this help",
+            KeyAction::Suspend => "Suspend",
+            KeyAction::Quit => "Quit",
+        }
+    }
+
+    /// Text of the shortcut bar, `None` for actions left out of it.
+    fn label(self) -> Option<&'static str> {
+        let label = match self {
+            KeyAction::Start => "Start",
+            KeyAction::Stop => "Stop",
+            KeyAction::Restart => "Restart",
+            KeyAction::Reload => "Reload",
+            KeyAction::ReloadOrRestart => "Reload or restart",
+            KeyAction::TryRestart => "Try restart",
+            KeyAction::ReloadOrTryRestart => "Reload or try restart",
+            KeyAction::Enable => "Enable",
+            KeyAction::Disable => "Disable",
+            KeyAction::ToggleUnitFilter => "List all units",
+            KeyAction::CycleStateFilter => "Filter",
+            KeyAction::ToggleMask => "Mask/Unmask",
+            KeyAction::Kill => "Kill",
+            KeyAction::ResourceLimits => "Resource limits",
+            KeyAction::ResetFailed => "Reset failed",
+            KeyAction::ResetAllFailed => "Reset all failed",
+            KeyAction::Refresh => "Refresh",
+            KeyAction::Hosts => "Hosts and containers",
+            KeyAction::Status => "Status",
+            KeyAction::Log => "Log",
+            KeyAction::Details => "Unit File",
+            KeyAction::NewUnit => "New unit",
+            KeyAction::Run => "Run command",
+            KeyAction::Overrides => "Overrides",
+            KeyAction::Processes => "Processes",
+            KeyAction::Boot => "Boot",
+            KeyAction::Targets => "Targets",
+            KeyAction::Jobs => "Jobs",
+            KeyAction::JobMode => "Job mode",
+            KeyAction::Exposure => "Exposure column",
+            KeyAction::Help => "Help",
+            KeyAction::MoveUp
+            | KeyAction::MoveDown
+            | KeyAction::PreviousTab
+            | KeyAction::NextTab
+            | KeyAction::PageUp
+            | KeyAction::PageDown
+            | KeyAction::Filter
+            | KeyAction::Suspend
+            | KeyAction::Quit => return None,
+        };
+        Some(label)
+    }
+}
+
+/// Commands of the other screens, bound in the `[view-keys]` table. Their keys only have to be unique
+/// within a screen, which also follows the `[keys]` bindings for moving, refreshing and opening views.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViewAction {
+    Back,
+    Confirm,
+    Cancel,
+    Open,
+    NextCategory,
+    Sort,
+    SetDefault,
+    Isolate,
+    CancelJob,
+    ClearJobs,
+    Edit,
+    EditExternal,
+    Diff,
+    Security,
+    Follow,
+}
+
+impl ViewAction {
+    pub const ALL: [ViewAction; 15] = [
+        ViewAction::Back,
+        ViewAction::Confirm,
+        ViewAction::Cancel,
+        ViewAction::Open,
+        ViewAction::NextCategory,
+        ViewAction::Sort,
+        ViewAction::SetDefault,
+        ViewAction::Isolate,
+        ViewAction::CancelJob,
+        ViewAction::ClearJobs,
+        ViewAction::Edit,
+        ViewAction::EditExternal,
+        ViewAction::Diff,
+        ViewAction::Security,
+        ViewAction::Follow,
+    ];
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            ViewAction::Back => &["q", "esc"],
+            ViewAction::Confirm => &["y", "enter"],
+            ViewAction::Cancel => &["n", "esc"],
+            ViewAction::Open => &["enter"],
+            ViewAction::NextCategory => &["tab"],
+            ViewAction::Sort => &["s"],
+            ViewAction::SetDefault => &["d"],
+            ViewAction::Isolate => &["i"],
+            ViewAction::CancelJob => &["c", "delete"],
+            ViewAction::ClearJobs => &["C"],
+            ViewAction::Edit => &["e"],
+            ViewAction::EditExternal => &["E"],
+            ViewAction::Diff => &["D"],
+            ViewAction::Security => &["S"],
+            ViewAction::Follow => &["a"],
+        }
+    }
+
+    /// Text of the help popup.
+    fn description(self) -> &'static str {
+        match self {
+            ViewAction::Back => "Go back",
+            ViewAction::Confirm => "Confirm",
+            ViewAction::Cancel => "Cancel",
+            ViewAction::Open => "Open the unit status or diff",
+            ViewAction::NextCategory => "Next boot chart or override category",
+            ViewAction::Sort => "Sort the boot blame",
+            ViewAction::SetDefault => "Set the default target",
+            ViewAction::Isolate => "Isolate a target",
+            ViewAction::CancelJob => "Cancel a job",
+            ViewAction::ClearJobs => "Clear all jobs",
+            ViewAction::Edit => "Edit unit file",
+            ViewAction::EditExternal => "Edit with systemctl edit",
+            ViewAction::Diff => "Diff unit against its vendor version",
+            ViewAction::Security => "Exposure breakdown",
+            ViewAction::Follow => "Toggle the log auto-refresh",
+        }
+    }
+}
+
+/// Screens besides the unit list, each with the actions it reacts to.
+#[derive(Clone, Copy, Debug)]
+enum View {
+    Status,
+    Processes,
+    Targets,
+    Jobs,
+    Boot,
+    Overrides,
+    Details,
+    Log,
+    Confirmation,
+}
+
+impl View {
+    const ALL: [View; 9] = [
+        View::Status,
+        View::Processes,
+        View::Targets,
+        View::Jobs,
+        View::Boot,
+        View::Overrides,
+        View::Details,
+        View::Log,
+        View::Confirmation,
+    ];
+
+    fn actions(self) -> (&'static [KeyAction], &'static [ViewAction]) {
+        const LIST: [KeyAction; 5] = [
+            KeyAction::MoveUp,
+            KeyAction::MoveDown,
+            KeyAction::PageUp,
+            KeyAction::PageDown,
+            KeyAction::Refresh,
+        ];
+        const PANES: [KeyAction; 6] = [
+            KeyAction::MoveUp,
+            KeyAction::MoveDown,
+            KeyAction::PageUp,
+            KeyAction::PageDown,
+            KeyAction::PreviousTab,
+            KeyAction::NextTab,
+        ];
+        match self {
+            View::Status => (
+                &[
+                    KeyAction::MoveUp,
+                    KeyAction::MoveDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::Refresh,
+                    KeyAction::Log,
+                    KeyAction::Details,
+                    KeyAction::Processes,
+                ],
+                &[ViewAction::Back],
+            ),
+            View::Processes => (
+                &[
+                    KeyAction::MoveUp,
+                    KeyAction::MoveDown,
+                    KeyAction::PageUp,
+                    KeyAction::PageDown,
+                    KeyAction::Refresh,
+                    KeyAction::Kill,
+                ],
+                &[ViewAction::Back],
+            ),
+            View::Targets => (
+                &LIST,
+                &[ViewAction::Back, ViewAction::Open, ViewAction::SetDefault, ViewAction::Isolate],
+            ),
+            View::Jobs => (&LIST, &[ViewAction::Back, ViewAction::CancelJob, ViewAction::ClearJobs]),
+            View::Boot => (
+                &LIST,
+                &[ViewAction::Back, ViewAction::Open, ViewAction::NextCategory, ViewAction::Sort],
+            ),
+            View::Overrides => (&LIST, &[ViewAction::Back, ViewAction::Open, ViewAction::NextCategory]),
+            View::Details => (
+                &PANES,
+                &[
+                    ViewAction::Back,
+                    ViewAction::Edit,
+                    ViewAction::EditExternal,
+                    ViewAction::Diff,
+                    ViewAction::Security,
+                ],
+            ),
+            View::Log => (&PANES, &[ViewAction::Back, ViewAction::Follow]),
+            View::Confirmation => (&[], &[ViewAction::Confirm, ViewAction::Cancel]),
+        }
+    }
+}
+
+/// A key with its modifiers, written like "s", "ctrl+f" or "pagedown" in the config file.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid key '{text}'");
+        // A lone "+" is the plus key rather than a separator.
+        let (modifier_names, key) = match text.rsplit_once('+') {
+            Some((modifiers, "")) => (modifiers.strip_suffix('+').unwrap_or(modifiers), "+"),
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", text),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            // Terminals send "shift+r" as an upper case R.
+            (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::Char(c.to_ascii_uppercase()),
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" => KeyCode::Enter,
+                "esc" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "space" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                name => name
+                    .strip_prefix('f')
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .filter(|number| (1..=12).contains(number))
+                    .map(KeyCode::F)
+                    .ok_or_else(invalid)?,
+            },
+        };
+        Ok(Self { code, modifiers })
+    }
+
+    /// Key event for a key as shown in the shortcut bars, like "Ctrl + c", "↓", "PgDn" or "E".
+    pub fn from_label(label: &str) -> Option<KeyEvent> {
+        let label: String = label.chars().filter(|c| !c.is_whitespace()).collect();
+        let (code, mut modifiers) = match label.as_str() {
+            "↑" => (KeyCode::Up, KeyModifiers::NONE),
+            "↓" => (KeyCode::Down, KeyModifiers::NONE),
+            "←" => (KeyCode::Left, KeyModifiers::NONE),
+            "→" => (KeyCode::Right, KeyModifiers::NONE),
+            "PgUp" => (KeyCode::PageUp, KeyModifiers::NONE),
+            "PgDn" => (KeyCode::PageDown, KeyModifiers::NONE),
+            "Shift+Tab" => (KeyCode::BackTab, KeyModifiers::SHIFT),
+            label => {
+                let binding = Self::parse(label).ok()?;
+                (binding.code, binding.modifiers)
+            }
+        };
+        // Terminals report upper case letters with Shift held.
+        if let KeyCode::Char(c) = code
+            && c.is_uppercase()
+        {
+            modifiers |= KeyModifiers::SHIFT;
+        }
+        Some(KeyEvent::new(code, modifiers))
+    }
+
+    fn types_text(self) -> bool {
+        matches!(self.code, KeyCode::Char(_)) && self.modifiers.difference(KeyModifiers::SHIFT).is_empty()
+    }
+
+    /// Shift is part of the character for letters and symbols, so it only has to match when bound explicitly.
+    fn matches(self, key: &KeyEvent) -> bool {
+        let pressed = if self.modifiers.contains(KeyModifiers::SHIFT) {
+            key.modifiers
+        } else {
+            key.modifiers.difference(KeyModifiers::SHIFT)
+        };
+        self.code == key.code && self.modifiers == pressed
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            code => write!(f, "{code}"),
+        }
+    }
+}
+
+/// One key or a list of keys for an action.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn parse(list: Option<&Self>, default_keys: &[&str]) -> Result<Vec<KeyBinding>, String> {
+        match list {
+            Some(KeyList::One(key)) => Ok(vec![KeyBinding::parse(key)?]),
+            Some(KeyList::Many(keys)) => keys.iter().map(|key| KeyBinding::parse(key)).collect(),
+            None => default_keys.iter().map(|key| KeyBinding::parse(key)).collect(),
+        }
+    }
+}
+
+/// Keys of every screen, with the bindings of the config file replacing the defaults per action.
+#[derive(Clone, Debug)]
+pub struct Keymap {
+    bindings: HashMap<KeyAction, Vec<KeyBinding>>,
+    view_bindings: HashMap<ViewAction, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&HashMap::new(), &HashMap::new()).expect("default keys are valid")
+    }
+}
+
+impl Keymap {
+    pub fn new(
+        overrides: &HashMap<KeyAction, KeyList>,
+        view_overrides: &HashMap<ViewAction, KeyList>,
+    ) -> Result<Self, String> {
+        let mut bindings = HashMap::new();
+        for action in KeyAction::ALL {
+            bindings.insert(action, KeyList::parse(overrides.get(&action), action.default_keys())?);
+        }
+        let mut view_bindings = HashMap::new();
+        for action in ViewAction::ALL {
+            view_bindings.insert(action, KeyList::parse(view_overrides.get(&action), action.default_keys())?);
+        }
+
+        // Checked on every screen, text inputs included, so they must not type anything.
+        for action in [KeyAction::Quit, KeyAction::Suspend] {
+            if let Some(key) = bindings[&action].iter().find(|key| key.types_text()) {
+                return Err(format!("Key {key} types text and can not be bound to {action:?}"));
+            }
+        }
+
+        let keymap = Self { bindings, view_bindings };
+        for (index, action) in KeyAction::ALL.iter().enumerate() {
+            for other in &KeyAction::ALL[index + 1..] {
+                if let Some(key) = keymap.bindings[action].iter().find(|key| keymap.bindings[other].contains(key)) {
+                    return Err(format!("Key {key} is bound to both {action:?} and {other:?}"));
+                }
+            }
+        }
+        for view in View::ALL {
+            let (actions, view_actions) = view.actions();
+            let mut seen: Vec<(KeyBinding, String)> = Vec::new();
+            let named = actions
+                .iter()
+                .map(|action| (&keymap.bindings[action], format!("{action:?}")))
+                .chain(view_actions.iter().map(|action| (&keymap.view_bindings[action], format!("{action:?}"))));
+            for (keys, name) in named {
+                for key in keys {
+                    if let Some((_, other)) = seen.iter().find(|(seen_key, _)| seen_key == key) {
+                        return Err(format!("Key {key} is bound to both {other} and {name} in the {view:?} screen"));
+                    }
+                    seen.push((*key, name.clone()));
+                }
+            }
+        }
+        Ok(keymap)
+    }
+
+    pub fn action(&self, key: &KeyEvent) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|action| self.bindings[action].iter().any(|binding| binding.matches(key)))
+    }
+
+    pub fn is(&self, key: &KeyEvent, action: KeyAction) -> bool {
+        self.bindings[&action].iter().any(|binding| binding.matches(key))
+    }
+
+    /// Rows a moving key goes down by, negative going up, for the lists of the other screens.
+    pub fn offset(&self, key: &KeyEvent) -> Option<isize> {
+        [
+            (KeyAction::MoveDown, 1),
+            (KeyAction::MoveUp, -1),
+            (KeyAction::PageDown, 10),
+            (KeyAction::PageUp, -10),
+        ]
+        .into_iter()
+        .find_map(|(action, offset)| self.is(key, action).then_some(offset))
+    }
+
+    pub fn is_view(&self, key: &KeyEvent, action: ViewAction) -> bool {
+        self.view_bindings[&action].iter().any(|binding| binding.matches(key))
+    }
+
+    /// Keys of an action as shown to the user, such as "↑/k".
+    pub fn keys(&self, action: KeyAction) -> String {
+        Self::join(&self.bindings[&action])
+    }
+
+    /// First key of an action, for the space-constrained shortcut bar.
+    pub fn key(&self, action: KeyAction) -> String {
+        self.bindings[&action].first().map_or_else(|| "unbound".to_string(), ToString::to_string)
+    }
+
+    /// Like `is_view` but leaves out keys that type text, for prompts with a text input.
+    pub fn is_view_command(&self, key: &KeyEvent, action: ViewAction) -> bool {
+        self.view_bindings[&action]
+            .iter()
+            .any(|binding| !binding.types_text() && binding.matches(key))
+    }
+
+    pub fn view_command_keys(&self, action: ViewAction) -> String {
+        let bindings: Vec<KeyBinding> = self.view_bindings[&action]
+            .iter()
+            .copied()
+            .filter(|binding| !binding.types_text())
+            .collect();
+        Self::join(&bindings)
+    }
+
+    pub fn view_keys(&self, action: ViewAction) -> String {
+        Self::join(&self.view_bindings[&action])
+    }
+
+    pub fn view_key(&self, action: ViewAction) -> String {
+        self.view_bindings[&action].first().map_or_else(|| "unbound".to_string(), ToString::to_string)
+    }
+
+    fn join(bindings: &[KeyBinding]) -> String {
+        let keys: Vec<String> = bindings.iter().map(ToString::to_string).collect();
+        if keys.is_empty() { "unbound".to_string() } else { keys.join("/") }
+    }
+
+    /// "↑/↓", the keys moving through the other screens.
+    pub fn move_keys(&self) -> String {
+        format!("{}/{}", self.key(KeyAction::MoveUp), self.key(KeyAction::MoveDown))
+    }
+
+    /// "←/→", the keys switching between the log and the unit file.
+    pub fn pane_keys(&self) -> String {
+        format!("{}/{}", self.key(KeyAction::PreviousTab), self.key(KeyAction::NextTab))
+    }
+
+    /// "y/Enter: confirm | n/Esc: cancel", the hint of confirmation prompts.
+    pub fn confirmation_hint(&self) -> String {
+        format!(
+            "{}: confirm | {}: cancel",
+            self.view_keys(ViewAction::Confirm),
+            self.view_keys(ViewAction::Cancel)
+        )
+    }
+
+    /// Shortcut bar of the unit list; `reload_available` is false for units without `CanReload`.
+    pub fn shortcut_line(&self, reload_available: bool) -> String {
+        let mut parts = vec![
+            format!("Navigate: {}/{}", self.key(KeyAction::MoveUp), self.key(KeyAction::MoveDown)),
+            format!("Switch tab: {}/{}", self.key(KeyAction::PreviousTab), self.key(KeyAction::NextTab)),
+        ];
+        for action in KeyAction::ALL {
+            match action.label() {
+                Some(label) if action == KeyAction::Reload && !reload_available => parts.push(format!("{label}: n/a")),
+                Some(label) => parts.push(format!("{label}: {}", self.key(action))),
+                None => {}
+            }
+        }
+        parts.join(" | ")
+    }
+
+    /// Sections of the help popup, two actions per line.
+    pub fn help_lines(&self) -> Vec<Line<'static>> {
+        let heading = Style::default().fg(theme().heading).add_modifier(Modifier::BOLD);
+        let mut lines = Vec::new();
+        for section in HelpSection::ALL {
+            lines.push(Line::from(vec![Span::styled(section.title(), heading)]));
+            let entries: Vec<String> = KeyAction::ALL
+                .into_iter()
+                .filter(|action| action.section() == section)
+                .map(|action| format!("{} - {}", self.keys(action), action.description()))
+                .collect();
+            for pair in entries.chunks(2) {
+                lines.push(Line::from(pair.join("    ")));
+            }
+            lines.push(Line::from(""));
+        }
+
+        lines.push(Line::from(vec![Span::styled("Other screens:", heading)]));
+        let entries: Vec<String> = ViewAction::ALL
+            .into_iter()
+            .map(|action| format!("{} - {}", self.view_keys(action), action.description()))
+            .collect();
+        for pair in entries.chunks(2) {
+            lines.push(Line::from(pair.join("    ")));
+        }
+        lines.push(Line::from(""));
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn parses_named_keys_and_modifiers() {
+        assert_eq!(KeyBinding::parse("ctrl+f").unwrap(), KeyBinding { code: KeyCode::Char('f'), modifiers: KeyModifiers::CONTROL });
+        assert_eq!(KeyBinding::parse("PageDown").unwrap(), KeyBinding { code: KeyCode::PageDown, modifiers: KeyModifiers::NONE });
+        assert_eq!(KeyBinding::parse("f5").unwrap(), KeyBinding { code: KeyCode::F(5), modifiers: KeyModifiers::NONE });
+        assert_eq!(KeyBinding::parse("ctrl++").unwrap(), KeyBinding { code: KeyCode::Char('+'), modifiers: KeyModifiers::CONTROL });
+        assert!(KeyBinding::parse("super+x").is_err());
+        assert!(KeyBinding::parse("f13").is_err());
+    }
+
+    #[test]
+    fn shift_with_a_letter_matches_the_upper_case_key() {
+        let binding = KeyBinding::parse("shift+r").unwrap();
+        assert!(binding.matches(&key(KeyCode::Char('R'), KeyModifiers::SHIFT)));
+        assert!(!binding.matches(&key(KeyCode::Char('r'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn upper_case_letters_match_with_or_without_shift_reported() {
+        let binding = KeyBinding::parse("R").unwrap();
+        assert!(binding.matches(&key(KeyCode::Char('R'), KeyModifiers::SHIFT)));
+        assert!(binding.matches(&key(KeyCode::Char('R'), KeyModifiers::NONE)));
+        assert!(!binding.matches(&key(KeyCode::Char('R'), KeyModifiers::CONTROL)));
+    }
+
+    #[test]
+    fn control_has_to_match() {
+        let binding = KeyBinding::parse("ctrl+c").unwrap();
+        assert!(binding.matches(&key(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+        assert!(!binding.matches(&key(KeyCode::Char('c'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn rejects_quit_on_a_key_that_types_text() {
+        let overrides = HashMap::from([(KeyAction::Quit, KeyList::One("q".to_string()))]);
+        assert!(Keymap::new(&overrides, &HashMap::new()).is_err());
+        let overrides = HashMap::from([(KeyAction::Quit, KeyList::One("ctrl+q".to_string()))]);
+        assert!(Keymap::new(&overrides, &HashMap::new()).is_ok());
+    }
+
+    #[test]
+    fn rejects_keys_bound_twice_in_a_screen() {
+        let overrides = HashMap::from([(ViewAction::Back, KeyList::One("u".to_string()))]);
+        assert!(Keymap::new(&HashMap::new(), &overrides).is_err());
+        // "d" disables units in the list and sets the default target in its own screen.
+        assert!(Keymap::default().is(&key(KeyCode::Char('d'), KeyModifiers::NONE), KeyAction::Disable));
+    }
+}
@@ -1,2 +1,6 @@
 pub mod app;
 pub mod components;
+pub mod keymap;
+pub mod mouse;
+pub mod tabs;
+pub mod theme;
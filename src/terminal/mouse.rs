@@ -0,0 +1,156 @@
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+use ratatui::text::{Line, Span};
+use std::time::{Duration, Instant};
+
+use crate::terminal::keymap::KeyBinding;
+
+/// Lines scrolled by one notch of the wheel.
+pub const WHEEL_LINES: u16 = 3;
+
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+const SEPARATOR: &str = " | ";
+
+/// An entry of a shortcut bar, such as "Log: l", where it was drawn.
+pub struct ShortcutEntry {
+    area: Rect,
+    text: String,
+}
+
+impl ShortcutEntry {
+    pub fn new(x: u16, y: u16, text: impl Into<String>) -> Self {
+        let text = text.into();
+        let width = u16::try_from(Span::raw(text.as_str()).width()).unwrap_or(u16::MAX);
+        Self { area: Rect::new(x, y, width, 1), text }
+    }
+
+    /// Key of the entry at a column: the alternative clicked in "Cancel job: c/Delete", or the first one.
+    fn key_at(&self, column: u16) -> Option<KeyEvent> {
+        let (label, keys) = self.text.rsplit_once(": ")?;
+        // Shown for actions the selected unit does not support.
+        if keys == "n/a" {
+            return None;
+        }
+        let clicked = usize::from(column - self.area.x);
+        let mut start = Span::raw(label).width() + 2;
+        let mut key = None;
+        for alternative in alternatives(keys) {
+            if key.is_none() || clicked >= start {
+                key = Some(alternative);
+            }
+            start += Span::raw(alternative).width() + 1;
+        }
+        KeyBinding::from_label(key?)
+    }
+}
+
+/// Splits "↑/k" into its keys, keeping "/" and "Ctrl+/" whole.
+fn alternatives(keys: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut previous = None;
+    for (index, c) in keys.char_indices() {
+        let is_separator = c == '/' && index > start && index + 1 < keys.len() && previous != Some('+');
+        if is_separator {
+            parts.push(&keys[start..index]);
+            start = index + 1;
+        }
+        previous = Some(c);
+    }
+    parts.push(&keys[start..]);
+    parts
+}
+
+/// Areas of the last drawn screen that react to clicks.
+#[derive(Default)]
+pub struct MouseAreas {
+    pub list: Rect,
+    pub tabs: Vec<Rect>,
+    pub shortcuts: Vec<ShortcutEntry>,
+}
+
+impl MouseAreas {
+    pub fn tab_at(&self, position: Position) -> Option<usize> {
+        self.tabs.iter().position(|area| area.contains(position))
+    }
+
+    pub fn shortcut_key_at(&self, position: Position) -> Option<KeyEvent> {
+        self.shortcuts
+            .iter()
+            .find(|entry| entry.area.contains(position))
+            .and_then(|entry| entry.key_at(position.x))
+    }
+}
+
+/// Columns of each title of a `Tabs` widget, with its default one space padding and divider.
+pub fn tab_areas(titles: &[Line<'_>], area: Rect) -> Vec<Rect> {
+    let mut x = area.x;
+    titles
+        .iter()
+        .map(|title| {
+            let width = u16::try_from(title.width()).unwrap_or(u16::MAX).saturating_add(2);
+            let tab = Rect::new(x, area.y, width, 1).intersection(area);
+            x = x.saturating_add(width).saturating_add(1);
+            tab
+        })
+        .collect()
+}
+
+/// Lays out shortcut lines in `area`, wrapping between entries rather than inside them so each one
+/// can be clicked. Lines without entries, like the "Actions" title, are kept as they are.
+/// Returns the lines, the entries and whether any line had to wrap.
+pub fn layout_shortcuts<'a>(lines: &[Line<'a>], area: Rect) -> (Vec<Line<'a>>, Vec<ShortcutEntry>, bool) {
+    let width = usize::from(area.width);
+    let mut rows: Vec<Line<'a>> = Vec::new();
+    let mut entries = Vec::new();
+    let mut wrapped = false;
+
+    for line in lines {
+        let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+        if !text.contains(": ") {
+            rows.push(line.clone());
+            continue;
+        }
+
+        let mut row = String::new();
+        for entry in text.split(SEPARATOR) {
+            let row_width = Span::raw(row.as_str()).width();
+            if !row.is_empty() && row_width + SEPARATOR.len() + Span::raw(entry).width() > width {
+                rows.push(Line::from(std::mem::take(&mut row)).style(line.style));
+                wrapped = true;
+            }
+            if !row.is_empty() {
+                row.push_str(SEPARATOR);
+            }
+            let x = area.x.saturating_add(u16::try_from(Span::raw(row.as_str()).width()).unwrap_or(u16::MAX));
+            let y = area.y.saturating_add(u16::try_from(rows.len()).unwrap_or(u16::MAX));
+            entries.push(ShortcutEntry::new(x, y, entry));
+            row.push_str(entry);
+        }
+        rows.push(Line::from(row).style(line.style));
+    }
+    entries.retain(|entry| entry.area.y < area.bottom());
+
+    (rows, entries, wrapped)
+}
+
+/// Tells a double click from two single ones on the same row.
+#[derive(Default)]
+pub struct ClickTracker {
+    last: Option<(Instant, Position)>,
+}
+
+impl ClickTracker {
+    pub fn is_double(&mut self, mouse: &MouseEvent) -> bool {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        let position = Position::new(mouse.column, mouse.row);
+        let double = self
+            .last
+            .is_some_and(|(at, last)| last.y == position.y && at.elapsed() <= DOUBLE_CLICK);
+        // A third click starts over instead of counting as another double click.
+        self.last = if double { None } else { Some((Instant::now(), position)) };
+        double
+    }
+}
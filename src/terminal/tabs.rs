@@ -0,0 +1,50 @@
+use crate::infrastructure::systemd_service_adapter::ConnectionType;
+use crate::terminal::components::list::ListCache;
+use crate::usecases::services_manager::ServicesManager;
+
+/// A tab of the unit list, bound to one bus connection.
+pub struct UnitTab {
+    pub label: String,
+    pub connection: ConnectionType,
+    /// Manager of the tab while another tab is shown, `None` until the tab is first opened.
+    pub manager: Option<ServicesManager>,
+    /// Units listed the last time the tab was shown.
+    pub cache: ListCache,
+}
+
+impl UnitTab {
+    pub fn new(label: impl Into<String>, connection: ConnectionType) -> Self {
+        Self {
+            label: label.into(),
+            connection,
+            manager: None,
+            cache: ListCache::default(),
+        }
+    }
+
+    pub fn remote(host: &str) -> Self {
+        Self::new(format!("System units @ {host}"), ConnectionType::Remote(host.to_string()))
+    }
+
+    pub fn machine(machine: &str) -> Self {
+        Self::new(format!("Container {machine}"), ConnectionType::Machine(machine.to_string()))
+    }
+
+    /// Tab for a connection picked in the host selector.
+    pub fn for_connection(connection: &ConnectionType) -> Self {
+        match connection {
+            ConnectionType::Remote(host) => Self::remote(host),
+            ConnectionType::Machine(machine) => Self::machine(machine),
+            ConnectionType::System => Self::new("System units", ConnectionType::System),
+            ConnectionType::Session => Self::new("Session units", ConnectionType::Session),
+        }
+    }
+}
+
+/// The local system and session buses, which are always available.
+pub fn default_tabs() -> Vec<UnitTab> {
+    vec![
+        UnitTab::new("System units", ConnectionType::System),
+        UnitTab::new("Session units", ConnectionType::Session),
+    ]
+}
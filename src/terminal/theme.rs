@@ -0,0 +1,210 @@
+use ratatui::style::{Color, Modifier, Style};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Theme chosen on startup, dark until one is set.
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::dark)
+}
+
+/// Sets the theme for the whole session; later calls are ignored.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// Colors of the interface by role, so a theme changes them everywhere at once.
+#[derive(Clone, Debug)]
+pub struct Theme {
+    /// Popup borders, unit names and the focused value.
+    pub accent: Color,
+    /// Text drawn on an `accent` background.
+    pub on_accent: Color,
+    /// Section titles, focused labels and the active tab.
+    pub heading: Color,
+    /// Title of the shortcut bars.
+    pub shortcut_heading: Color,
+    pub text: Color,
+    /// Secondary text such as descriptions and key hints.
+    pub muted: Color,
+    /// Placeholders, inactive units and disabled rows.
+    pub dim: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// Units redirected by a symlink in the overrides view.
+    pub special: Color,
+    pub selection_bg: Color,
+    pub selection_fg: Color,
+    /// Selected row while the list ignores keys, such as during a job.
+    pub inactive_selection_bg: Color,
+    /// Border of the log while it follows new lines.
+    pub log_follow: Color,
+    pub comment: Color,
+    pub section: Color,
+    pub directive: Color,
+}
+
+impl Theme {
+    pub const BUILT_IN: [&str; 4] = ["dark", "light", "high-contrast", "monochrome"];
+
+    pub fn dark() -> Self {
+        Self {
+            accent: Color::Cyan,
+            on_accent: Color::Black,
+            heading: Color::Yellow,
+            shortcut_heading: Color::LightMagenta,
+            text: Color::White,
+            muted: Color::Gray,
+            dim: Color::DarkGray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            special: Color::Magenta,
+            selection_bg: Color::Blue,
+            selection_fg: Color::White,
+            inactive_selection_bg: Color::DarkGray,
+            log_follow: Color::Rgb(255, 165, 0),
+            comment: Color::Green,
+            section: Color::LightBlue,
+            directive: Color::Yellow,
+        }
+    }
+
+    /// For terminals with a light background, where yellow and gray text fade out.
+    pub fn light() -> Self {
+        Self {
+            accent: Color::Blue,
+            on_accent: Color::White,
+            heading: Color::Magenta,
+            shortcut_heading: Color::Magenta,
+            text: Color::Black,
+            muted: Color::DarkGray,
+            dim: Color::Gray,
+            success: Color::Green,
+            warning: Color::Rgb(175, 95, 0),
+            error: Color::Red,
+            special: Color::Magenta,
+            selection_bg: Color::Blue,
+            selection_fg: Color::White,
+            inactive_selection_bg: Color::Gray,
+            log_follow: Color::Rgb(215, 95, 0),
+            comment: Color::Green,
+            section: Color::Blue,
+            directive: Color::Rgb(175, 95, 0),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            accent: Color::LightCyan,
+            on_accent: Color::Black,
+            heading: Color::LightYellow,
+            shortcut_heading: Color::LightMagenta,
+            text: Color::White,
+            muted: Color::White,
+            dim: Color::Gray,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            special: Color::LightMagenta,
+            selection_bg: Color::Yellow,
+            selection_fg: Color::Black,
+            inactive_selection_bg: Color::White,
+            log_follow: Color::LightRed,
+            comment: Color::LightGreen,
+            section: Color::LightCyan,
+            directive: Color::LightYellow,
+        }
+    }
+
+    /// No colors at all, following NO_COLOR; the selection is shown reversed.
+    pub fn monochrome() -> Self {
+        Self {
+            accent: Color::Reset,
+            on_accent: Color::Reset,
+            heading: Color::Reset,
+            shortcut_heading: Color::Reset,
+            text: Color::Reset,
+            muted: Color::Reset,
+            dim: Color::Reset,
+            success: Color::Reset,
+            warning: Color::Reset,
+            error: Color::Reset,
+            special: Color::Reset,
+            selection_bg: Color::Reset,
+            selection_fg: Color::Reset,
+            inactive_selection_bg: Color::Reset,
+            log_follow: Color::Reset,
+            comment: Color::Reset,
+            section: Color::Reset,
+            directive: Color::Reset,
+        }
+    }
+
+    pub fn built_in(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "monochrome" => Some(Self::monochrome()),
+            _ => None,
+        }
+    }
+
+    /// Builds a user theme from its `[themes.NAME]` table: an optional `base` theme and colors by role,
+    /// written as names ("light-blue"), indexes ("208") or hex ("#ff8700").
+    pub fn from_table(table: &HashMap<String, String>) -> Result<Self, String> {
+        let base = table.get("base").map_or("dark", String::as_str);
+        let mut theme = Self::built_in(base).ok_or_else(|| format!("Unknown base theme '{base}'"))?;
+        for (role, value) in table.iter().filter(|(role, _)| *role != "base") {
+            let color = Color::from_str(value).map_err(|_| format!("Invalid color '{value}' for {role}"))?;
+            *theme.role_mut(role).ok_or_else(|| format!("Unknown theme color '{role}'"))? = color;
+        }
+        Ok(theme)
+    }
+
+    fn role_mut(&mut self, role: &str) -> Option<&mut Color> {
+        let color = match role {
+            "accent" => &mut self.accent,
+            "on-accent" => &mut self.on_accent,
+            "heading" => &mut self.heading,
+            "shortcut-heading" => &mut self.shortcut_heading,
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "dim" => &mut self.dim,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "special" => &mut self.special,
+            "selection-bg" => &mut self.selection_bg,
+            "selection-fg" => &mut self.selection_fg,
+            "inactive-selection-bg" => &mut self.inactive_selection_bg,
+            "log-follow" => &mut self.log_follow,
+            "comment" => &mut self.comment,
+            "section" => &mut self.section,
+            "directive" => &mut self.directive,
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// Highlighted row of tables; reversed when the theme has no selection color.
+    pub fn selection(&self) -> Style {
+        self.row_highlight(self.selection_bg, self.selection_fg)
+    }
+
+    pub fn inactive_selection(&self) -> Style {
+        self.row_highlight(self.inactive_selection_bg, self.selection_fg)
+    }
+
+    fn row_highlight(&self, bg: Color, fg: Color) -> Style {
+        if bg == Color::Reset {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        } else {
+            Style::default().bg(bg).fg(fg).add_modifier(Modifier::BOLD)
+        }
+    }
+}
@@ -1,15 +1,36 @@
+use crate::domain::boot_analysis::{BootTimestamps, UnitActivation};
 use crate::domain::service::Service;
 use crate::domain::service_repository::ServiceRepository;
-use crate::infrastructure::systemd_service_adapter::ConnectionType;
+use crate::domain::service_state::ServiceState;
+use crate::domain::transient_unit::TransientUnit;
+use crate::domain::unit_diff::{UnitDelta, UnitOverride};
+use crate::domain::unit_job::{JobMode, JobOperation, UnitJob};
+use crate::domain::unit_directives::Diagnostic;
+use crate::domain::unit_process::UnitProcess;
+use crate::domain::unit_resources::ResourceControl;
+use crate::domain::unit_security::{evaluate, SecurityReport};
+use crate::domain::unit_signal::KillTarget;
+use crate::domain::unit_status::UnitStatus;
+use crate::domain::unit_target::TargetUnit;
 use crate::terminal::components::list::QueryUnitFile;
 use std::error::Error;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::mpsc::Sender;
 use std::thread;
+use std::time::{Duration, Instant};
 use std::sync::{Arc, Mutex};
 
+const JOB_POLL_INTERVAL: u64 = 100;
+/// Longest wait for a unit to settle after its job, systemd's default start timeout. A unit still
+/// activating by then, such as one in an auto-restart loop, is reported as it is.
+const JOB_SETTLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Repository shared with background threads; its identity tells which tab a result belongs to.
+pub type RepositoryHandle = Arc<Mutex<Box<dyn ServiceRepository>>>;
+
 pub struct ServicesManager {
-    repository: Arc<Mutex<Box<dyn ServiceRepository>>>,
+    repository: RepositoryHandle,
 }
 
 impl ServicesManager {
@@ -17,19 +38,78 @@ impl ServicesManager {
         Self { repository: Arc::new(Mutex::new(repository)) }
     }
 
-    pub fn start_service(&self, service: &Service) -> Result<Service, Box<dyn Error>> {
-        let service = self.repository.lock().unwrap().start_service(service.name())?;
-        Ok(service)
+    /// Queues a job for the unit on a background thread and calls `on_done` once the unit has settled.
+    ///
+    /// The repository lock is only held for each D-Bus call, so a slow job does not block other screens.
+    pub fn queue_job<F>(&self, operation: JobOperation, mode: JobMode, service: &Service, on_done: F)
+    where
+        F: FnOnce(Result<Service, String>) + Send + 'static,
+    {
+        let repository = self.repository.clone();
+        let name = service.name().to_string();
+        thread::spawn(move || {
+            let queued = {
+                let repository = repository.lock().unwrap();
+                match operation {
+                    JobOperation::Start => repository.start_service(&name, mode),
+                    JobOperation::Stop => repository.stop_service(&name, mode),
+                    JobOperation::Restart => repository.restart_service(&name, mode),
+                    JobOperation::Reload => repository.reload_service(&name, mode),
+                    JobOperation::ReloadOrRestart => repository.reload_or_restart_service(&name, mode),
+                    JobOperation::TryRestart => repository.try_restart_service(&name, mode),
+                    JobOperation::ReloadOrTryRestart => repository.reload_or_try_restart_service(&name, mode),
+                }
+                .map_err(|e| e.to_string())
+            };
+
+            let deadline = Instant::now() + JOB_SETTLE_TIMEOUT;
+            let result = queued.and_then(|mut service| {
+                while service.state().active().ends_with("ing") && Instant::now() < deadline {
+                    thread::sleep(Duration::from_millis(JOB_POLL_INTERVAL));
+                    service = repository.lock().unwrap().get_unit(&name).map_err(|e| e.to_string())?;
+                }
+                Ok(service)
+            });
+            on_done(result);
+        });
     }
 
-    pub fn stop_service(&self, service: &Service) -> Result<Service, Box<dyn Error>> {
-        let service = self.repository.lock().unwrap().stop_service(service.name())?;
-        Ok(service)
+    /// Starts a transient unit and returns the unit whose log shows the command output.
+    pub fn run_transient_unit(&self, unit: &TransientUnit) -> Result<Service, Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        let name = repository.start_transient_unit(unit)?;
+        // A service waiting for its timer is not loaded yet, but its log can already be followed.
+        Ok(repository
+            .get_unit(&name)
+            .unwrap_or_else(|_| Service::new(name.clone(), unit.command.trim().to_string(), ServiceState::default())))
     }
 
-    pub fn restart_service(&self, service: &Service) -> Result<Service, Box<dyn Error>> {
-        let service = self.repository.lock().unwrap().restart_service(service.name())?;
-        Ok(service)
+    pub fn resource_controls(&self, service: &Service) -> Result<Vec<(ResourceControl, u64)>, Box<dyn Error>> {
+        self.repository.lock().unwrap().resource_controls(service.name())
+    }
+
+    pub fn set_resource_controls(&self, service: &Service, runtime: bool, values: &[(ResourceControl, u64)]) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().set_resource_controls(service.name(), runtime, values)
+    }
+
+    pub fn list_jobs(&self) -> Result<Vec<UnitJob>, Box<dyn Error>> {
+        self.repository.lock().unwrap().list_jobs()
+    }
+
+    pub fn cancel_job(&self, id: u32) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().cancel_job(id)
+    }
+
+    pub fn clear_jobs(&self) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().clear_jobs()
+    }
+
+    pub fn watch_jobs(&self, on_change: Box<dyn Fn() + Send>) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().watch_jobs(on_change)
+    }
+
+    pub fn unwatch_jobs(&self) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().unwatch_jobs()
     }
 
     pub fn enable_service(&self, service: &Service) -> Result<Service, Box<dyn Error>> {
@@ -54,6 +134,28 @@ impl ServicesManager {
         Ok(service)
     }
 
+    pub fn reset_failed_service(&self, service: &Service) -> Result<Service, Box<dyn Error>> {
+        self.repository.lock().unwrap().reset_failed_unit(service.name())
+    }
+
+    pub fn reset_all_failed(&self) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().reset_failed()
+    }
+
+    pub fn kill_service(&self, service: &Service, target: KillTarget, signal: i32) -> Result<Service, Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        repository.kill_unit(service.name(), target.as_str(), signal)?;
+        repository.get_unit(service.name())
+    }
+
+    pub fn unit_processes(&self, service: &Service) -> Result<Vec<UnitProcess>, Box<dyn Error>> {
+        self.repository.lock().unwrap().unit_processes(service.name())
+    }
+
+    pub fn kill_process(&self, pid: u32, signal: i32) -> Result<(), Box<dyn Error>> {
+        self.repository.lock().unwrap().kill_process(pid, signal)
+    }
+
     pub fn list_services(&self, filter: bool, tx: Arc<Sender<QueryUnitFile>>) -> Result<Vec<Service>, Box<dyn Error>> {
         let mut all = Vec::new();
 
@@ -90,21 +192,146 @@ impl ServicesManager {
         Ok(all)
     }
 
-    pub fn get_log(&self, service: &Service) -> Result<String, Box<dyn Error>> {
-        self.repository.lock().unwrap().get_service_log(service.name())
+    pub fn get_unit(&self, name: &str) -> Result<Service, Box<dyn Error>> {
+        self.repository.lock().unwrap().get_unit(name)
     }
 
-    pub fn change_repository_connection(&mut self, connection_type: ConnectionType) -> Result<(), Box<dyn Error>> {
-        self.repository.lock().unwrap().change_connection(connection_type)?;
-        Ok(())
+    pub fn get_log(&self, service: &Service, lines: usize) -> Result<String, Box<dyn Error>> {
+        self.repository.lock().unwrap().get_service_log(service.name(), lines)
+    }
+
+    pub fn get_log_tail(&self, service: &Service, lines: usize) -> Result<String, Box<dyn Error>> {
+        self.repository.lock().unwrap().get_service_log_tail(service.name(), lines)
+    }
+
+    pub fn unit_status(&self, service: &Service) -> Result<UnitStatus, Box<dyn Error>> {
+        self.repository.lock().unwrap().unit_status(service.name())
+    }
+
+    pub fn default_target(&self) -> Result<String, Box<dyn Error>> {
+        self.repository.lock().unwrap().default_target()
+    }
+
+    pub fn set_default_target(&self, name: &str) -> Result<(), Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        repository.set_default_target(name)?;
+        repository.reload_daemon()
+    }
+
+    pub fn list_targets(&self) -> Result<Vec<TargetUnit>, Box<dyn Error>> {
+        self.repository.lock().unwrap().list_targets()
+    }
+
+    pub fn isolate_target(&self, name: &str) -> Result<Service, Box<dyn Error>> {
+        self.repository.lock().unwrap().isolate_target(name)
+    }
+
+    pub fn boot_timestamps(&self) -> Result<BootTimestamps, Box<dyn Error>> {
+        self.repository.lock().unwrap().boot_timestamps()
+    }
+
+    pub fn unit_activations(&self) -> Result<Vec<UnitActivation>, Box<dyn Error>> {
+        self.repository.lock().unwrap().unit_activations()
+    }
+
+    pub fn security_report(&self, service: &Service) -> Result<SecurityReport, Box<dyn Error>> {
+        let settings = self.repository.lock().unwrap().security_settings(service.name())?;
+        Ok(evaluate(&settings))
     }
 
     pub fn systemctl_cat(&self, service: &Service) -> Result<String, Box<dyn Error>> {
         self.repository.lock().unwrap().systemctl_cat(service.name())
     }
 
-    pub fn repository_handle(&self) -> Arc<Mutex<Box<dyn ServiceRepository>>> {
+    pub fn unit_file_directory(&self) -> Result<PathBuf, Box<dyn Error>> {
+        self.repository.lock().unwrap().unit_file_directory()
+    }
+
+    pub fn create_unit_files(&self, files: &[(String, String)]) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        let directory = repository.unit_file_directory()?;
+        if let Some(path) = files.iter().map(|(file_name, _)| directory.join(file_name)).find(|path| path.exists()) {
+            return Err(format!("{} already exists", path.display()).into());
+        }
+
+        let mut written = Vec::new();
+        for (file_name, content) in files {
+            match repository.create_unit_file(file_name, content) {
+                Ok(path) => written.push(path),
+                Err(e) => {
+                    // Leave no half created unit behind, such as a service without its timer.
+                    for path in &written {
+                        let _ = std::fs::remove_file(path);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        repository.reload_daemon()?;
+        Ok(written)
+    }
+
+    pub fn load_unit_file(&self, service: &Service) -> Result<String, Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        let path = repository.unit_fragment_path(service.name())?;
+        if path.is_empty() {
+            return Err(format!("Unit '{}' has no unit file", service.name()).into());
+        }
+        repository.read_unit_file(&path)
+    }
+
+    pub fn verify_unit_file(&self, service: &Service, content: &str) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+        self.repository.lock().unwrap().verify_unit_file(service.name(), content)
+    }
+
+    pub fn save_unit_file(&self, service: &Service, content: &str) -> Result<PathBuf, Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        let path = repository.write_unit_file(service.name(), content)?;
+        repository.reload_daemon()?;
+        Ok(path)
+    }
+
+    pub fn unit_delta(&self, service: &Service) -> Result<UnitDelta, Box<dyn Error>> {
+        let repository = self.repository.lock().unwrap();
+        let fragment_path = repository.unit_fragment_path(service.name())?;
+        let drop_in_paths = repository.unit_drop_in_paths(service.name())?;
+        let vendor_path = repository.vendor_unit_path(service.name());
+
+        let vendor = match &vendor_path {
+            Some(path) => repository.read_unit_file(path)?,
+            None => String::new(),
+        };
+
+        let mut effective = if fragment_path.is_empty() {
+            String::new()
+        } else {
+            repository.read_unit_file(&fragment_path)?
+        };
+        for path in &drop_in_paths {
+            effective.push_str(&format!("\n# {path}\n"));
+            effective.push_str(&repository.read_unit_file(path)?);
+        }
+
+        Ok(UnitDelta {
+            vendor_path,
+            fragment_path,
+            drop_in_paths,
+            vendor,
+            effective,
+        })
+    }
+
+    pub fn list_unit_overrides(&self) -> Result<Vec<UnitOverride>, Box<dyn Error>> {
+        self.repository.lock().unwrap().list_unit_overrides()
+    }
+
+    pub fn repository_handle(&self) -> RepositoryHandle {
         Arc::clone(&self.repository)
     }
+
+    /// Whether results tagged with `repository` came from this manager's bus.
+    pub fn owns(&self, repository: &RepositoryHandle) -> bool {
+        Arc::ptr_eq(&self.repository, repository)
+    }
 }
 